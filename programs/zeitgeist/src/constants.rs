@@ -6,6 +6,8 @@
 pub const PLATFORM_FEE_BPS: u16 = 200; // 2% platform fee
 pub const MIN_PREDICTION_AMOUNT: u64 = 1_000_000; // 0.001 SOL minimum bet
 pub const MAX_PREDICTION_AMOUNT: u64 = 100_000_000_000; // 100 SOL maximum bet
+pub const DEFAULT_EXIT_FEE_BPS: u16 = 100; // 1% fee for withdrawing before betting closes
+pub const MAX_EXIT_FEE_BPS: u16 = 1000; // 10% maximum exit fee
 
 // Time limits (in seconds)
 pub const MIN_BETTING_DURATION: i64 = 60; // 1 minute minimum
@@ -13,16 +15,91 @@ pub const MAX_BETTING_DURATION: i64 = 86400 * 7; // 7 days maximum
 pub const SETTLEMENT_TIMEOUT: i64 = 86400; // 24 hours to settle after betting closes
 
 pub const BETTING_WINDOW_DURATION: i64 = 10; // 10 seconds betting window
+pub const MIN_BETTING_WINDOW_DURATION: i64 = 10; // 10 seconds minimum window
+pub const MAX_BETTING_WINDOW_DURATION: i64 = 300; // 5 minutes maximum window
+pub const MAX_SESSION_DURATION: i64 = 86400; // Session keys last at most 24 hours
 
 
 // Round configuration
 pub const MAX_PREDICTIONS_PER_ROUND: u32 = 10000;
 pub const MAX_OUTCOMES: u8 = 10; // Maximum number of possible outcomes
 
+// Round series configuration
+pub const SERIES_ROUND_ID_SHIFT: u32 = 32; // Spawned round ids are (series_id << 32) | index
+pub const AUTO_ROUND_ID_BASE: u64 = 1 << 31; // create_round_auto ids are AUTO_ROUND_ID_BASE + counter; manual ids stay below it
+
 // Tournament configuration
 pub const MAX_TOURNAMENT_ROUNDS: u8 = 20;
 pub const MIN_TOURNAMENT_ENTRY_FEE: u64 = 10_000_000; // 0.01 SOL
 
+// Pause flags (bitmask over instruction families)
+pub const PAUSE_BETTING: u8 = 1 << 0; // place_prediction
+pub const PAUSE_ROUND_CREATION: u8 = 1 << 1; // create_round, create_tournament
+pub const PAUSE_SETTLEMENT: u8 = 1 << 2; // close_betting, settle_round
+pub const PAUSE_CLAIMS: u8 = 1 << 3; // claim_winnings
+pub const PAUSE_REFUNDS: u8 = 1 << 4; // refund_prediction
+pub const PAUSE_MINTING: u8 = 1 << 5; // mint_moment_card
+pub const PAUSE_ALL: u8 = PAUSE_BETTING
+    | PAUSE_ROUND_CREATION
+    | PAUSE_SETTLEMENT
+    | PAUSE_CLAIMS
+    | PAUSE_REFUNDS
+    | PAUSE_MINTING;
+
+// AMM market configuration
+pub const MIN_AMM_LIQUIDITY: u64 = 100_000_000; // 0.1 SOL minimum creator liquidity
+
+// Outcome tokens (one token unit per lamport of collateral)
+pub const OUTCOME_TOKEN_DECIMALS: u8 = 9;
+
+// Parlays
+pub const MIN_PARLAY_LEGS: usize = 2;
+pub const MAX_PARLAY_LEGS: usize = 5;
+pub const MAX_PARLAY_MULTIPLIER_BPS: u64 = 1_000_000; // 100x cap on combined odds
+pub const MAX_PARLAY_LEG_MULTIPLIER_BPS: u64 = 50_000; // 5x cap on any single leg
+
+// Gamification
+pub const XP_PER_PREDICTION: u64 = 10;
+pub const XP_PER_WIN: u64 = 50;
+pub const XP_PER_LOSS: u64 = 5;
+pub const XP_PER_TOURNAMENT_WIN: u64 = 500;
+pub const XP_PER_LEVEL_BASE: u64 = 100; // Level n needs 100 * (n - 1)^2 XP
+pub const HEATMAP_DAYS: usize = 7; // Rounds-played buckets, one per day
+pub const SECONDS_PER_DAY: i64 = 86400;
+pub const STREAK_ACHIEVEMENT_LENGTH: u32 = 10;
+
+// Achievement bitflags (UserStats.achievements)
+pub const ACHIEVEMENT_FIRST_WIN: u32 = 1 << 0;
+pub const ACHIEVEMENT_STREAK_10: u32 = 1 << 1;
+pub const ACHIEVEMENT_LEGENDARY_MOMENT: u32 = 1 << 2;
+pub const ACHIEVEMENT_TOURNAMENT_CHAMPION: u32 = 1 << 3;
+
+// Moment cards
+pub const DEFAULT_MOMENT_URI_BASE: &str = "https://api.zeitgeist.game/moments";
+pub const MAX_MOMENT_URI_BASE_LEN: usize = 80; // Leaves room for "/{card PDA}" within Bubblegum's 200-byte URI
+pub const MPL_TOKEN_METADATA_ID: anchor_lang::prelude::Pubkey =
+    anchor_lang::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+pub const RARITY_TIER_COUNT: usize = 4; // Common, Rare, Epic, Legendary
+pub const MAX_RARITY_NAME_LEN: usize = 16;
+pub const DEFAULT_EPIC_ODDS_BPS: u16 = 3999; // Winning outcome had under 40% of the pool
+pub const DEFAULT_LEGENDARY_ODDS_BPS: u16 = 1999; // Winning outcome had under 20% of the pool
+// Holder perks by card rarity (Common, Rare, Epic, Legendary), one use per card per day
+pub const MOMENT_PERK_FEE_DISCOUNT_BPS: [u16; RARITY_TIER_COUNT] = [0, 2500, 5000, 10000]; // Share of the platform fee rebated
+pub const MOMENT_PERK_BOOST_BPS: [u16; RARITY_TIER_COUNT] = [0, 0, 0, 100]; // Bonus on winnings
+pub const MOMENT_COMBINE_COUNT: usize = 3; // Cards of one tier burned to forge one of the next tier
+pub const MOMENT_EVOLVE_WINS: usize = 3; // Later wins presented to evolve a card one tier
+// Anchor discriminator of spl-account-compression's `verify_leaf`
+pub const VERIFY_LEAF_DISCRIMINATOR: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+pub const BUBBLEGUM_COLLECTION_CPI_SEED: &[u8] = b"collection_cpi"; // Bubblegum's collection signer PDA
+
+// Seasons
+pub const LEADERBOARD_SIZE: usize = 10; // Ranked entries kept per season
+// Share of a season's reward pool paid to each leaderboard rank; unfilled ranks return to the platform wallet
+pub const SEASON_REWARD_SHARES_BPS: [u16; LEADERBOARD_SIZE] = [3000, 2000, 1500, 1000, 800, 600, 400, 300, 250, 150];
+
+// Access control
+pub const MAX_ROLE_MEMBERS: usize = 10; // Maximum distinct keys holding any role
+
 // PDA seeds
 pub const GLOBAL_STATE_SEED: &[u8] = b"global_state";
 pub const ROUND_SEED: &[u8] = b"round";
@@ -30,10 +107,23 @@ pub const PREDICTION_SEED: &[u8] = b"prediction";
 pub const USER_STATS_SEED: &[u8] = b"user_stats";
 pub const TOURNAMENT_SEED: &[u8] = b"tournament";
 pub const VAULT_SEED: &[u8] = b"vault";
-
-// Account space calculations (discriminator + data)
-pub const GLOBAL_STATE_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 1 + 1; // ~66 bytes
-pub const ROUND_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 32 + 8 + 8 + 1; // ~160 bytes
-pub const PREDICTION_SIZE: usize = 8 + 32 + 32 + 8 + 1 + 8 + 1 + 1; // ~100 bytes
-pub const USER_STATS_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 8; // ~96 bytes
-pub const TOURNAMENT_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1 + 1 + 8 + 8; // ~120 bytes
+pub const ROLES_SEED: &[u8] = b"roles";
+pub const ROUND_SERIES_SEED: &[u8] = b"round_series";
+pub const ROUND_LOOKUP_SEED: &[u8] = b"round_lookup";
+pub const AMM_MARKET_SEED: &[u8] = b"amm_market";
+pub const AMM_VAULT_SEED: &[u8] = b"amm_vault";
+pub const AMM_POSITION_SEED: &[u8] = b"amm_position";
+pub const OUTCOME_MINT_SEED: &[u8] = b"outcome_mint";
+pub const PARLAY_SEED: &[u8] = b"parlay";
+pub const PARLAY_POOL_SEED: &[u8] = b"parlay_pool";
+pub const PARLAY_VAULT_SEED: &[u8] = b"parlay_vault";
+pub const SESSION_TOKEN_SEED: &[u8] = b"session_token";
+pub const BALANCE_SEED: &[u8] = b"balance";
+pub const SEASON_SEED: &[u8] = b"season";
+pub const SEASON_ENTRY_SEED: &[u8] = b"season_entry";
+pub const SEASON_VAULT_SEED: &[u8] = b"season_vault";
+pub const MOMENT_CARD_SEED: &[u8] = b"moment_card";
+pub const MOMENT_CONFIG_SEED: &[u8] = b"moment_config";
+pub const MOMENT_AUTHORITY_SEED: &[u8] = b"moment_authority";
+pub const MOMENT_UPGRADES_SEED: &[u8] = b"moment_upgrades";
+pub const PERK_VAULT_SEED: &[u8] = b"perk_vault";
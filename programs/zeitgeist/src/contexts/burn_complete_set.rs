@@ -0,0 +1,41 @@
+// Burn complete set context
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use crate::state::{GlobalState, Round};
+use crate::constants::*;
+
+/// Remaining accounts: one `(outcome mint, token account)` pair per outcome, in order
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct BurnCompleteSet<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_BETTING) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [ROUND_SEED, round_id.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = !round.frozen @ crate::errors::SocialRouletteError::RoundFrozen
+    )]
+    pub round: Account<'info, Round>,
+    
+    /// CHECK: Vault PDA holding round funds
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, round_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,52 @@
+// Buy AMM shares context
+
+use anchor_lang::prelude::*;
+use crate::state::{AmmMarket, AmmPosition, GlobalState};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(market_id: u64)]
+pub struct BuyAmmShares<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_BETTING) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [AMM_MARKET_SEED, market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, AmmMarket>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + AmmPosition::INIT_SPACE,
+        seeds = [AMM_POSITION_SEED, market_id.to_le_bytes().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub position: Account<'info, AmmPosition>,
+    
+    /// CHECK: Vault PDA holding market collateral
+    #[account(
+        mut,
+        seeds = [AMM_VAULT_SEED, market_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    /// CHECK: Platform fee recipient
+    #[account(
+        mut,
+        constraint = platform_wallet.key() == global_state.platform_wallet @ crate::errors::SocialRouletteError::Unauthorized
+    )]
+    pub platform_wallet: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
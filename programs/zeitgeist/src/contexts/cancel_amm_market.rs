@@ -0,0 +1,31 @@
+// Cancel AMM market context
+
+use anchor_lang::prelude::*;
+use crate::state::{AmmMarket, GlobalState, Roles};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(market_id: u64)]
+pub struct CancelAmmMarket<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(
+        mut,
+        seeds = [AMM_MARKET_SEED, market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, AmmMarket>,
+    
+    /// Settler, or anyone once the market's settlement window has passed
+    pub authority: Signer<'info>,
+}
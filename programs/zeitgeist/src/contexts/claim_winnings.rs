@@ -1,24 +1,33 @@
 // Claim winnings context
 
 use anchor_lang::prelude::*;
-use crate::state::{Round, Prediction, UserStats};
+use crate::state::{Balance, GlobalState, Round, Prediction, UserStats};
 use crate::constants::*;
 
 #[derive(Accounts)]
 #[instruction(round_id: u64)]
 pub struct ClaimWinnings<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_CLAIMS) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
     #[account(
         seeds = [ROUND_SEED, round_id.to_le_bytes().as_ref()],
-        bump = round.bump
+        bump = round.bump,
+        constraint = !round.frozen @ crate::errors::SocialRouletteError::RoundFrozen
     )]
     pub round: Account<'info, Round>,
     
+    /// Stays open, marked claimed, so the winner can still mint its moment card;
+    /// mint_moment_card or close_prediction returns the rent
     #[account(
         mut,
         seeds = [PREDICTION_SEED, round_id.to_le_bytes().as_ref(), user.key().as_ref()],
         bump = prediction.bump,
-        constraint = prediction.user == user.key(),
-        close = user
+        constraint = prediction.user == user.key()
     )]
     pub prediction: Account<'info, Prediction>,
     
@@ -40,5 +49,21 @@ pub struct ClaimWinnings<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
     
+    /// Optional custodial balance to pay into instead of the wallet
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, user.key().as_ref()],
+        bump = balance.bump
+    )]
+    pub balance: Option<Account<'info, Balance>>,
+    
+    /// Pays moment card holder perks; required when the prediction has one
+    #[account(
+        mut,
+        seeds = [PERK_VAULT_SEED],
+        bump
+    )]
+    pub perk_vault: Option<SystemAccount<'info>>,
+    
     pub system_program: Program<'info, System>,
 }
@@ -1,7 +1,7 @@
 // Close betting context
 
 use anchor_lang::prelude::*;
-use crate::state::{GlobalState, Round};
+use crate::state::{GlobalState, Role, Roles, Round};
 use crate::constants::*;
 
 #[derive(Accounts)]
@@ -9,14 +9,23 @@ use crate::constants::*;
 pub struct CloseBetting<'info> {
     #[account(
         seeds = [GLOBAL_STATE_SEED],
-        bump = global_state.bump
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_SETTLEMENT) @ crate::errors::SocialRouletteError::ProgramPaused
     )]
     pub global_state: Account<'info, GlobalState>,
     
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Settler, &authority.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
     #[account(
         mut,
         seeds = [ROUND_SEED, round_id.to_le_bytes().as_ref()],
-        bump = round.bump
+        bump = round.bump,
+        constraint = !round.frozen @ crate::errors::SocialRouletteError::RoundFrozen
     )]
     pub round: Account<'info, Round>,
     
@@ -0,0 +1,22 @@
+// Close prediction context
+
+use anchor_lang::prelude::*;
+use crate::state::Prediction;
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct ClosePrediction<'info> {
+    #[account(
+        mut,
+        seeds = [PREDICTION_SEED, round_id.to_le_bytes().as_ref(), user.key().as_ref()],
+        bump = prediction.bump,
+        constraint = prediction.user == user.key(),
+        constraint = prediction.claimed @ crate::errors::SocialRouletteError::PredictionNotClaimed,
+        close = user
+    )]
+    pub prediction: Account<'info, Prediction>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
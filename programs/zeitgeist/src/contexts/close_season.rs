@@ -0,0 +1,41 @@
+// Close season context
+
+use anchor_lang::prelude::*;
+use crate::state::{GlobalState, Season};
+use crate::constants::*;
+
+/// Permissionless crank; ranked players then claim with `claim_season_reward`
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct CloseSeason<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_CLAIMS) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, season_id.to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+    
+    /// CHECK: Vault PDA holding the season's reward pool
+    #[account(
+        mut,
+        seeds = [SEASON_VAULT_SEED, season_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    /// CHECK: Treasury receiving unallocated rewards
+    #[account(
+        mut,
+        constraint = platform_wallet.key() == global_state.platform_wallet @ crate::errors::SocialRouletteError::Unauthorized
+    )]
+    pub platform_wallet: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
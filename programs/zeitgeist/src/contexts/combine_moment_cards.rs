@@ -0,0 +1,100 @@
+// Combine moment cards context
+
+use anchor_lang::prelude::*;
+use mpl_bubblegum::programs::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID};
+use crate::state::{GlobalState, MomentConfig, MomentUpgrades};
+use crate::constants::*;
+
+/// Remaining accounts, for each of `MOMENT_COMBINE_COUNT` cards of one tier:
+/// `(moment_card, merkle_tree, tree_authority, proof nodes...)` with `proof_len`
+/// proof nodes. The first card's record is reforged into the new card.
+#[derive(Accounts)]
+pub struct CombineMomentCards<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MINTING) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+    
+    #[account(
+        seeds = [MOMENT_CONFIG_SEED],
+        bump = moment_config.bump
+    )]
+    pub moment_config: Box<Account<'info, MomentConfig>>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + MomentUpgrades::INIT_SPACE,
+        seeds = [MOMENT_UPGRADES_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub moment_upgrades: Box<Account<'info, MomentUpgrades>>,
+    
+    /// CHECK: PDA signing as tree creator and collection authority
+    #[account(
+        seeds = [MOMENT_AUTHORITY_SEED],
+        bump = moment_config.authority_bump
+    )]
+    pub moment_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Current moment card tree, which the forged card is minted into
+    #[account(
+        mut,
+        address = moment_config.merkle_tree @ crate::errors::SocialRouletteError::InvalidMomentTree
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+    
+    /// CHECK: Bubblegum tree config PDA for `merkle_tree`
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        seeds::program = bubblegum_program.key(),
+        bump
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Collection mint
+    #[account(
+        address = moment_config.collection_mint @ crate::errors::SocialRouletteError::InvalidMomentCollection
+    )]
+    pub collection_mint: UncheckedAccount<'info>,
+    
+    /// CHECK: Collection metadata, validated by Bubblegum - must be mutable for Bubblegum
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Collection master edition, validated by Bubblegum
+    pub collection_edition: UncheckedAccount<'info>,
+    
+    /// CHECK: Bubblegum's collection signer PDA
+    #[account(
+        seeds = [BUBBLEGUM_COLLECTION_CPI_SEED],
+        seeds::program = bubblegum_program.key(),
+        bump
+    )]
+    pub bubblegum_signer: UncheckedAccount<'info>,
+    
+    /// Holder of every burned card
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// CHECK: Bubblegum program
+    #[account(address = mpl_bubblegum::ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL Account Compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL Noop program
+    #[account(address = SPL_NOOP_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = MPL_TOKEN_METADATA_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,32 @@
+// Complete tournament context
+
+use anchor_lang::prelude::*;
+use crate::state::{Role, Roles, Tournament, UserStats};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u64, winner: Pubkey)]
+pub struct CompleteTournament<'info> {
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Settler, &authority.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_SEED, tournament_id.to_le_bytes().as_ref()],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+    
+    #[account(
+        mut,
+        seeds = [USER_STATS_SEED, winner.as_ref()],
+        bump = winner_stats.bump
+    )]
+    pub winner_stats: Account<'info, UserStats>,
+    
+    pub authority: Signer<'info>,
+}
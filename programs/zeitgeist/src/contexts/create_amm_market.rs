@@ -0,0 +1,45 @@
+// Create AMM market context
+
+use anchor_lang::prelude::*;
+use crate::state::{AmmMarket, GlobalState, Role, Roles};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(market_id: u64)]
+pub struct CreateAmmMarket<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_ROUND_CREATION) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::RoundCreator, &creator.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + AmmMarket::INIT_SPACE,
+        seeds = [AMM_MARKET_SEED, market_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub market: Account<'info, AmmMarket>,
+    
+    /// CHECK: Vault PDA that will hold market collateral
+    #[account(
+        mut,
+        seeds = [AMM_VAULT_SEED, market_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
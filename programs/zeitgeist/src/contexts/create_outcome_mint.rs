@@ -0,0 +1,44 @@
+// Create outcome mint context
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use crate::state::{GlobalState, Round};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(round_id: u64, outcome: u8)]
+pub struct CreateOutcomeMint<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_ROUND_CREATION) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [ROUND_SEED, round_id.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = round.creator == creator.key() @ crate::errors::SocialRouletteError::Unauthorized,
+        constraint = !round.frozen @ crate::errors::SocialRouletteError::RoundFrozen
+    )]
+    pub round: Account<'info, Round>,
+    
+    /// SPL mint for `outcome`; the round PDA is the mint authority
+    #[account(
+        init,
+        payer = creator,
+        seeds = [OUTCOME_MINT_SEED, round_id.to_le_bytes().as_ref(), &[outcome]],
+        bump,
+        mint::decimals = OUTCOME_TOKEN_DECIMALS,
+        mint::authority = round
+    )]
+    pub outcome_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
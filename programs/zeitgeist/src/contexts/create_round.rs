@@ -1,7 +1,7 @@
 // Create round context
 
 use anchor_lang::prelude::*;
-use crate::state::{GlobalState, Round};
+use crate::state::{GlobalState, Role, Roles, Round};
 use crate::constants::*;
 
 #[derive(Accounts)]
@@ -11,10 +11,17 @@ pub struct CreateRound<'info> {
         mut,
         seeds = [GLOBAL_STATE_SEED],
         bump = global_state.bump,
-        constraint = !global_state.paused @ crate::errors::SocialRouletteError::ProgramPaused  // ✅ Add pause check
+        constraint = !global_state.is_paused(PAUSE_ROUND_CREATION) @ crate::errors::SocialRouletteError::ProgramPaused
     )]
     pub global_state: Account<'info, GlobalState>,
     
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::RoundCreator, &creator.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
     #[account(
         init,
         payer = creator,
@@ -0,0 +1,58 @@
+// Create round with a program-assigned id context
+
+use anchor_lang::prelude::*;
+use crate::state::{GlobalState, Role, Roles, Round, RoundLookup};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(client_nonce: u64)]
+pub struct CreateRoundAuto<'info> {
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_ROUND_CREATION) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::RoundCreator, &creator.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    /// Round id is the next auto round id, so concurrent creators can't collide
+    /// and manual ids can't squat it
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Round::INIT_SPACE,
+        seeds = [ROUND_SEED, Round::auto_round_id(global_state.auto_rounds).to_le_bytes().as_ref()],
+        bump
+    )]
+    pub round: Account<'info, Round>,
+    
+    /// Fails if the nonce was already used, so retries never create a second round
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + RoundLookup::INIT_SPACE,
+        seeds = [ROUND_LOOKUP_SEED, creator.key().as_ref(), client_nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub round_lookup: Account<'info, RoundLookup>,
+    
+    /// CHECK: Vault PDA that will hold round funds
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, Round::auto_round_id(global_state.auto_rounds).to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
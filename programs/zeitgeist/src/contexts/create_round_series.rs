@@ -0,0 +1,37 @@
+// Create round series context
+
+use anchor_lang::prelude::*;
+use crate::state::{GlobalState, Role, Roles, RoundSeries};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(series_id: u64)]
+pub struct CreateRoundSeries<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_ROUND_CREATION) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::RoundCreator, &creator.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + RoundSeries::INIT_SPACE,
+        seeds = [ROUND_SERIES_SEED, series_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub series: Account<'info, RoundSeries>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,38 @@
+// Create season context
+
+use anchor_lang::prelude::*;
+use crate::state::{Role, Roles, Season};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct CreateSeason<'info> {
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Treasurer, &treasurer.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(
+        init,
+        payer = treasurer,
+        space = 8 + Season::INIT_SPACE,
+        seeds = [SEASON_SEED, season_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+    
+    /// CHECK: Vault PDA holding the season's reward pool
+    #[account(
+        mut,
+        seeds = [SEASON_VAULT_SEED, season_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,31 @@
+// Create session context
+
+use anchor_lang::prelude::*;
+use crate::state::SessionToken;
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(session_key: Pubkey)]
+pub struct CreateSession<'info> {
+    /// Re-creating a session for the same key renews it and resets its spend
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + SessionToken::INIT_SPACE,
+        seeds = [SESSION_TOKEN_SEED, user.key().as_ref(), session_key.as_ref()],
+        bump
+    )]
+    pub session_token: Account<'info, SessionToken>,
+    
+    /// Ephemeral session key; only receives the optional top-up
+    #[account(
+        mut,
+        address = session_key
+    )]
+    pub session_signer: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,22 @@
+// Deposit balance context
+
+use anchor_lang::prelude::*;
+use crate::state::Balance;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct DepositBalance<'info> {
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + Balance::INIT_SPACE,
+        seeds = [BALANCE_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub balance: Account<'info, Balance>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
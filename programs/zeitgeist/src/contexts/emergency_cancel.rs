@@ -1,7 +1,7 @@
 // Emergency cancel context
 
 use anchor_lang::prelude::*;
-use crate::state::{GlobalState, Round};
+use crate::state::{GlobalState, Role, Roles, Round};
 use crate::constants::*;
 
 #[derive(Accounts)]
@@ -9,11 +9,17 @@ use crate::constants::*;
 pub struct EmergencyCancel<'info> {
     #[account(
         seeds = [GLOBAL_STATE_SEED],
-        bump = global_state.bump,
-        constraint = global_state.admin == admin.key() @ crate::errors::SocialRouletteError::Unauthorized
+        bump = global_state.bump
     )]
     pub global_state: Account<'info, GlobalState>,
     
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Settler, &authority.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
     #[account(
         mut,
         seeds = [ROUND_SEED, round_id.to_le_bytes().as_ref()],
@@ -21,5 +27,5 @@ pub struct EmergencyCancel<'info> {
     )]
     pub round: Account<'info, Round>,
     
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
 }
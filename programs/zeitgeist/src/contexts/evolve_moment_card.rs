@@ -0,0 +1,51 @@
+// Evolve moment card context
+
+use anchor_lang::prelude::*;
+use mpl_bubblegum::programs::SPL_ACCOUNT_COMPRESSION_ID;
+use crate::state::{GlobalState, MomentCard, MomentUpgrades};
+use crate::constants::*;
+
+/// Remaining accounts: `proof_len` proof nodes for the card's leaf, then
+/// `(win_card, merkle_tree, proof...)` for each of `MOMENT_EVOLVE_WINS` cards
+/// the signer holds recording wins in later rounds
+#[derive(Accounts)]
+pub struct EvolveMomentCard<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MINTING) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+    
+    #[account(
+        mut,
+        seeds = [MOMENT_CARD_SEED, moment_card.prediction.as_ref()],
+        bump = moment_card.bump
+    )]
+    pub moment_card: Box<Account<'info, MomentCard>>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + MomentUpgrades::INIT_SPACE,
+        seeds = [MOMENT_UPGRADES_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub moment_upgrades: Box<Account<'info, MomentUpgrades>>,
+    
+    /// CHECK: Tree holding the card
+    #[account(
+        address = moment_card.merkle_tree @ crate::errors::SocialRouletteError::InvalidMomentTree
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+    
+    /// Current holder of the card
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// CHECK: SPL Account Compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
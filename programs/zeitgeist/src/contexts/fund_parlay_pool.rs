@@ -0,0 +1,37 @@
+// Fund parlay pool context
+
+use anchor_lang::prelude::*;
+use crate::state::{ParlayPool, Role, Roles};
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct FundParlayPool<'info> {
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Treasurer, &treasurer.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(
+        init_if_needed,
+        payer = treasurer,
+        space = 8 + ParlayPool::INIT_SPACE,
+        seeds = [PARLAY_POOL_SEED],
+        bump
+    )]
+    pub parlay_pool: Account<'info, ParlayPool>,
+    
+    /// CHECK: Vault PDA holding the parlay bankroll and stakes
+    #[account(
+        mut,
+        seeds = [PARLAY_VAULT_SEED],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
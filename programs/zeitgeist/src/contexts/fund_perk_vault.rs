@@ -0,0 +1,28 @@
+// Fund perk vault context
+
+use anchor_lang::prelude::*;
+use crate::state::{Role, Roles};
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct FundPerkVault<'info> {
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Treasurer, &treasurer.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    /// CHECK: Vault PDA paying moment card holder perks
+    #[account(
+        mut,
+        seeds = [PERK_VAULT_SEED],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
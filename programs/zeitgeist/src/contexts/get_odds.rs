@@ -0,0 +1,21 @@
+// Get odds context (read-only)
+
+use anchor_lang::prelude::*;
+use crate::state::{GlobalState, Round};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct GetOdds<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        seeds = [ROUND_SEED, round_id.to_le_bytes().as_ref()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, Round>,
+}
@@ -0,0 +1,24 @@
+// Grant role context
+
+use anchor_lang::prelude::*;
+use crate::state::{GlobalState, Roles};
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = global_state.admin == admin.key() @ crate::errors::SocialRouletteError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [ROLES_SEED],
+        bump = roles.bump
+    )]
+    pub roles: Account<'info, Roles>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,69 @@
+// Init moment tree context
+
+use anchor_lang::prelude::*;
+use mpl_bubblegum::programs::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID};
+use crate::state::{GlobalState, MomentConfig};
+use crate::constants::*;
+
+/// Also used to roll over to a fresh tree (or collection) once one fills up
+#[derive(Accounts)]
+pub struct InitMomentTree<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = global_state.admin == admin.key() @ crate::errors::SocialRouletteError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + MomentConfig::INIT_SPACE,
+        seeds = [MOMENT_CONFIG_SEED],
+        bump
+    )]
+    pub moment_config: Account<'info, MomentConfig>,
+    
+    /// CHECK: PDA signing as tree creator and collection authority
+    #[account(
+        seeds = [MOMENT_AUTHORITY_SEED],
+        bump
+    )]
+    pub moment_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Bubblegum tree config PDA, created by the CPI
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        seeds::program = bubblegum_program.key(),
+        bump
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Merkle tree account, pre-allocated by the admin and owned by the compression program
+    #[account(
+        mut,
+        owner = SPL_ACCOUNT_COMPRESSION_ID @ crate::errors::SocialRouletteError::InvalidMomentTree
+    )]
+    pub merkle_tree: UncheckedAccount<'info>,
+    
+    /// CHECK: Collection mint whose update authority is `moment_authority`
+    pub collection_mint: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// CHECK: Bubblegum program
+    #[account(address = mpl_bubblegum::ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL Account Compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    
+    /// CHECK: SPL Noop program
+    #[account(address = SPL_NOOP_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
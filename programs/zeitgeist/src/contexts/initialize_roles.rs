@@ -0,0 +1,29 @@
+// Initialize roles context
+
+use anchor_lang::prelude::*;
+use crate::state::{GlobalState, Roles};
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct InitializeRoles<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = global_state.admin == admin.key() @ crate::errors::SocialRouletteError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Roles::INIT_SPACE,
+        seeds = [ROLES_SEED],
+        bump
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,35 @@
+// Join season context
+
+use anchor_lang::prelude::*;
+use crate::state::{Season, SeasonEntry, UserStats};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct JoinSeason<'info> {
+    #[account(
+        seeds = [SEASON_SEED, season_id.to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = 8 + SeasonEntry::INIT_SPACE,
+        seeds = [SEASON_ENTRY_SEED, season_id.to_le_bytes().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub season_entry: Account<'info, SeasonEntry>,
+    
+    #[account(
+        seeds = [USER_STATS_SEED, user.key().as_ref()],
+        bump = user_stats.bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
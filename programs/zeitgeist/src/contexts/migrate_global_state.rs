@@ -0,0 +1,21 @@
+// Migrate global state context
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct MigrateGlobalState<'info> {
+    /// CHECK: May still be on an old layout; deserialized after realloc and
+    /// admin is checked against the migrated data
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATE_SEED],
+        bump
+    )]
+    pub global_state: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,29 @@
+// Migrate prediction context
+
+use anchor_lang::prelude::*;
+use crate::state::GlobalState;
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(round_id: u64, user: Pubkey)]
+pub struct MigratePrediction<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = global_state.admin == admin.key() @ crate::errors::SocialRouletteError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    /// CHECK: May still be on an old layout; deserialized after realloc
+    #[account(
+        mut,
+        seeds = [PREDICTION_SEED, round_id.to_le_bytes().as_ref(), user.as_ref()],
+        bump
+    )]
+    pub prediction: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,29 @@
+// Migrate tournament context
+
+use anchor_lang::prelude::*;
+use crate::state::GlobalState;
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u64)]
+pub struct MigrateTournament<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = global_state.admin == admin.key() @ crate::errors::SocialRouletteError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    /// CHECK: May still be on an old layout; deserialized after realloc
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_SEED, tournament_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub tournament: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,29 @@
+// Migrate user stats context
+
+use anchor_lang::prelude::*;
+use crate::state::GlobalState;
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(user: Pubkey)]
+pub struct MigrateUserStats<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = global_state.admin == admin.key() @ crate::errors::SocialRouletteError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    /// CHECK: May still be on an old layout; deserialized after realloc
+    #[account(
+        mut,
+        seeds = [USER_STATS_SEED, user.as_ref()],
+        bump
+    )]
+    pub user_stats: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
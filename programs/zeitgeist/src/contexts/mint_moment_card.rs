@@ -1,53 +1,119 @@
 use anchor_lang::prelude::*;
-use crate::state::{Round, Prediction};
+use mpl_bubblegum::programs::{SPL_ACCOUNT_COMPRESSION_ID, SPL_NOOP_ID};
+use crate::state::{GlobalState, MomentCard, MomentConfig, Round, Prediction, UserStats};
 use crate::constants::*;
 
 #[derive(Accounts)]
 #[instruction(round_id: u64)]
 pub struct MintMomentCard<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MINTING) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+    
     #[account(
         seeds = [ROUND_SEED, round_id.to_le_bytes().as_ref()],
-        bump = round.bump
+        bump = round.bump,
+        constraint = !round.frozen @ crate::errors::SocialRouletteError::RoundFrozen
     )]
-    pub round: Account<'info, Round>,
+    pub round: Box<Account<'info, Round>>,
     
+    /// Closed to `user` after minting if already claimed (by claim_winnings or distribute_winnings)
     #[account(
+        mut,
         seeds = [PREDICTION_SEED, round_id.to_le_bytes().as_ref(), user.key().as_ref()],
         bump = prediction.bump,
         constraint = prediction.user == user.key()
     )]
     pub prediction: Account<'info, Prediction>,
     
+    /// One card per prediction: `init` fails if this prediction was already minted
+    #[account(
+        init,
+        payer = user,
+        space = 8 + MomentCard::INIT_SPACE,
+        seeds = [MOMENT_CARD_SEED, prediction.key().as_ref()],
+        bump
+    )]
+    pub moment_card: Box<Account<'info, MomentCard>>,
+    
+    #[account(
+        mut,
+        seeds = [USER_STATS_SEED, user.key().as_ref()],
+        bump = user_stats.bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+    
+    #[account(
+        seeds = [MOMENT_CONFIG_SEED],
+        bump = moment_config.bump
+    )]
+    pub moment_config: Box<Account<'info, MomentConfig>>,
+    
+    /// CHECK: PDA signing as tree creator and collection authority
+    #[account(
+        seeds = [MOMENT_AUTHORITY_SEED],
+        bump = moment_config.authority_bump
+    )]
+    pub moment_authority: UncheckedAccount<'info>,
+    
     /// CHECK: Merkle tree account - must be mutable for Bubblegum
-    #[account(mut)]
+    #[account(
+        mut,
+        address = moment_config.merkle_tree @ crate::errors::SocialRouletteError::InvalidMomentTree
+    )]
     pub merkle_tree: UncheckedAccount<'info>,
     
-    /// CHECK: Tree authority PDA - must be mutable for Bubblegum
-    #[account(mut)]  // ← ADD THIS
+    /// CHECK: Bubblegum tree config PDA for `merkle_tree` - must be mutable for Bubblegum
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        seeds::program = bubblegum_program.key(),
+        bump
+    )]
     pub tree_authority: UncheckedAccount<'info>,
     
     /// CHECK: Collection mint
+    #[account(
+        address = moment_config.collection_mint @ crate::errors::SocialRouletteError::InvalidMomentCollection
+    )]
     pub collection_mint: UncheckedAccount<'info>,
     
-    /// CHECK: Collection metadata - must be mutable for Bubblegum
-    #[account(mut)]  // ← ADD THIS
+    /// CHECK: Collection metadata, validated by Bubblegum - must be mutable for Bubblegum
+    #[account(mut)]
     pub collection_metadata: UncheckedAccount<'info>,
     
-    /// CHECK: Collection master edition - must be mutable for Bubblegum
-    #[account(mut)]  // ← ADD THIS
+    /// CHECK: Collection master edition, validated by Bubblegum
     pub collection_edition: UncheckedAccount<'info>,
     
+    /// CHECK: Bubblegum's collection signer PDA
+    #[account(
+        seeds = [BUBBLEGUM_COLLECTION_CPI_SEED],
+        seeds::program = bubblegum_program.key(),
+        bump
+    )]
+    pub bubblegum_signer: UncheckedAccount<'info>,
+    
     #[account(mut)]
     pub user: Signer<'info>,
     
     /// CHECK: Bubblegum program
+    #[account(address = mpl_bubblegum::ID)]
     pub bubblegum_program: UncheckedAccount<'info>,
     
     /// CHECK: SPL Account Compression program
+    #[account(address = SPL_ACCOUNT_COMPRESSION_ID)]
     pub compression_program: UncheckedAccount<'info>,
     
     /// CHECK: SPL Noop program
+    #[account(address = SPL_NOOP_ID)]
     pub log_wrapper: UncheckedAccount<'info>,
     
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = MPL_TOKEN_METADATA_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
@@ -12,6 +12,59 @@ pub mod refund_prediction;  // ← ADD THIS
 pub mod unpause_program;  // ← ADD THIS
 pub mod pause_program;
 pub mod mint_moment_card;
+pub mod initialize_roles;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod update_platform_wallet;
+pub mod set_round_frozen;
+pub mod migrate_global_state;
+pub mod migrate_round;
+pub mod migrate_prediction;
+pub mod migrate_user_stats;
+pub mod migrate_tournament;
+pub mod create_round_series;
+pub mod spawn_next_round;
+pub mod set_round_series_active;
+pub mod create_round_auto;
+pub mod withdraw_prediction;
+pub mod set_exit_fee;
+pub mod get_odds;
+pub mod create_amm_market;
+pub mod buy_amm_shares;
+pub mod sell_amm_shares;
+pub mod settle_amm_market;
+pub mod redeem_amm_shares;
+pub mod withdraw_amm_liquidity;
+pub mod create_outcome_mint;
+pub mod mint_complete_set;
+pub mod burn_complete_set;
+pub mod redeem_outcome_tokens;
+pub mod fund_parlay_pool;
+pub mod withdraw_parlay_pool;
+pub mod place_parlay;
+pub mod settle_parlay;
+pub mod create_session;
+pub mod revoke_session;
+pub mod deposit_balance;
+pub mod withdraw_balance;
+pub mod distribute_winnings;
+pub mod refund_predictions;
+pub mod record_loss;
+pub mod complete_tournament;
+pub mod create_season;
+pub mod join_season;
+pub mod submit_season_score;
+pub mod close_season;
+pub mod set_moment_uri_base;
+pub mod init_moment_tree;
+pub mod set_moment_rarity_config;
+pub mod combine_moment_cards;
+pub mod evolve_moment_card;
+pub mod fund_perk_vault;
+pub mod cancel_amm_market;
+pub mod refund_outcome_tokens;
+pub mod close_prediction;
+pub mod claim_season_reward;
 
 
 // Re-export all contexts
@@ -27,3 +80,56 @@ pub use refund_prediction::*;  // ← ADD THIS
 pub use unpause_program::*;  // ← ADD THIS
 pub use pause_program::*;
 pub use mint_moment_card::*;
+pub use initialize_roles::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use update_platform_wallet::*;
+pub use set_round_frozen::*;
+pub use migrate_global_state::*;
+pub use migrate_round::*;
+pub use migrate_prediction::*;
+pub use migrate_user_stats::*;
+pub use migrate_tournament::*;
+pub use create_round_series::*;
+pub use spawn_next_round::*;
+pub use set_round_series_active::*;
+pub use create_round_auto::*;
+pub use withdraw_prediction::*;
+pub use set_exit_fee::*;
+pub use get_odds::*;
+pub use create_amm_market::*;
+pub use buy_amm_shares::*;
+pub use sell_amm_shares::*;
+pub use settle_amm_market::*;
+pub use redeem_amm_shares::*;
+pub use withdraw_amm_liquidity::*;
+pub use create_outcome_mint::*;
+pub use mint_complete_set::*;
+pub use burn_complete_set::*;
+pub use redeem_outcome_tokens::*;
+pub use fund_parlay_pool::*;
+pub use withdraw_parlay_pool::*;
+pub use place_parlay::*;
+pub use settle_parlay::*;
+pub use create_session::*;
+pub use revoke_session::*;
+pub use deposit_balance::*;
+pub use withdraw_balance::*;
+pub use distribute_winnings::*;
+pub use refund_predictions::*;
+pub use record_loss::*;
+pub use complete_tournament::*;
+pub use create_season::*;
+pub use join_season::*;
+pub use submit_season_score::*;
+pub use close_season::*;
+pub use set_moment_uri_base::*;
+pub use init_moment_tree::*;
+pub use set_moment_rarity_config::*;
+pub use combine_moment_cards::*;
+pub use evolve_moment_card::*;
+pub use fund_perk_vault::*;
+pub use cancel_amm_market::*;
+pub use refund_outcome_tokens::*;
+pub use close_prediction::*;
+pub use claim_season_reward::*;
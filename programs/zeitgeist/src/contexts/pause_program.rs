@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::GlobalState;
+use crate::state::{GlobalState, Role, Roles};
 use crate::constants::*;
 
 #[derive(Accounts)]
@@ -7,10 +7,16 @@ pub struct PauseProgram<'info> {
     #[account(
         mut,
         seeds = [GLOBAL_STATE_SEED],
-        bump = global_state.bump,
-        constraint = global_state.admin == admin.key() @ crate::errors::SocialRouletteError::Unauthorized
+        bump = global_state.bump
     )]
     pub global_state: Account<'info, GlobalState>,
     
-    pub admin: Signer<'info>,
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Pauser, &authority.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    pub authority: Signer<'info>,
 }
\ No newline at end of file
@@ -0,0 +1,46 @@
+// Place parlay context
+
+use anchor_lang::prelude::*;
+use crate::state::{GlobalState, Parlay, ParlayPool};
+use crate::constants::*;
+
+/// Remaining accounts: the `Round` for each pick, in pick order
+#[derive(Accounts)]
+#[instruction(parlay_id: u64)]
+pub struct PlaceParlay<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_BETTING) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [PARLAY_POOL_SEED],
+        bump = parlay_pool.bump
+    )]
+    pub parlay_pool: Account<'info, ParlayPool>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = 8 + Parlay::INIT_SPACE,
+        seeds = [PARLAY_SEED, user.key().as_ref(), parlay_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub parlay: Account<'info, Parlay>,
+    
+    /// CHECK: Vault PDA holding the parlay bankroll and stakes
+    #[account(
+        mut,
+        seeds = [PARLAY_VAULT_SEED],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
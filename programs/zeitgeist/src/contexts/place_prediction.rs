@@ -1,7 +1,7 @@
 // Place prediction context
 
 use anchor_lang::prelude::*;
-use crate::state::{GlobalState, Round, Prediction, UserStats};
+use crate::state::{Balance, GlobalState, MomentCard, Round, Prediction, SessionToken, UserStats};
 use crate::constants::*;
 
 #[derive(Accounts)]
@@ -10,19 +10,21 @@ pub struct PlacePrediction<'info> {
     #[account(
         seeds = [GLOBAL_STATE_SEED],
         bump = global_state.bump,
-        constraint = !global_state.paused @ crate::errors::SocialRouletteError::ProgramPaused  // ✅ Add pause check
+        constraint = !global_state.is_paused(PAUSE_BETTING) @ crate::errors::SocialRouletteError::ProgramPaused
     )]
     pub global_state: Account<'info, GlobalState>,
     
     #[account(
         mut,
         seeds = [ROUND_SEED, round_id.to_le_bytes().as_ref()],
-        bump = round.bump
+        bump = round.bump,
+        constraint = !round.frozen @ crate::errors::SocialRouletteError::RoundFrozen
     )]
     pub round: Account<'info, Round>,
     
+    /// One position per user per round; later bets top it up or hedge other outcomes
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         space = 8 + Prediction::INIT_SPACE,
         seeds = [
@@ -51,11 +53,45 @@ pub struct PlacePrediction<'info> {
     )]
     pub vault: AccountInfo<'info>,
     
+    /// The user, or a session key authorized by `session_token`
     #[account(mut)]
     pub payer: Signer<'info>,
     
-    /// CHECK: User account for PDA derivation (can be anyone in custodial mode)
+    /// CHECK: User the prediction is attributed to; must be `payer` or have
+    /// delegated to `payer` through `session_token`
     pub user: AccountInfo<'info>,
     
+    /// Required when `payer` is a session key rather than the user
+    #[account(
+        mut,
+        seeds = [SESSION_TOKEN_SEED, user.key().as_ref(), payer.key().as_ref()],
+        bump = session_token.bump
+    )]
+    pub session_token: Option<Account<'info, SessionToken>>,
+    
+    /// Optional custodial balance to bet from instead of the wallet
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, user.key().as_ref()],
+        bump = balance.bump
+    )]
+    pub balance: Option<Account<'info, Balance>>,
+    
+    /// Optional moment card held by `user`, for its holder perk; ownership is
+    /// proven against `card_merkle_tree` with the proof nodes as remaining accounts
+    #[account(
+        mut,
+        seeds = [MOMENT_CARD_SEED, moment_card.prediction.as_ref()],
+        bump = moment_card.bump,
+        constraint = !moment_card.burned @ crate::errors::SocialRouletteError::InvalidMomentCard
+    )]
+    pub moment_card: Option<Box<Account<'info, MomentCard>>>,
+    
+    /// CHECK: The card's merkle tree; checked against the card record and by the proof
+    pub card_merkle_tree: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Checked against the SPL account compression program id
+    pub compression_program: Option<UncheckedAccount<'info>>,
+    
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
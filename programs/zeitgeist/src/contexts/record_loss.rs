@@ -0,0 +1,42 @@
+// Record loss context
+
+use anchor_lang::prelude::*;
+use crate::state::{GlobalState, Round, Prediction, UserStats};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct RecordLoss<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_CLAIMS) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        seeds = [ROUND_SEED, round_id.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = !round.frozen @ crate::errors::SocialRouletteError::RoundFrozen
+    )]
+    pub round: Account<'info, Round>,
+    
+    #[account(
+        mut,
+        seeds = [PREDICTION_SEED, round_id.to_le_bytes().as_ref(), user.key().as_ref()],
+        bump = prediction.bump,
+        constraint = prediction.user == user.key(),
+        close = user
+    )]
+    pub prediction: Account<'info, Prediction>,
+    
+    #[account(
+        mut,
+        seeds = [USER_STATS_SEED, user.key().as_ref()],
+        bump = user_stats.bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
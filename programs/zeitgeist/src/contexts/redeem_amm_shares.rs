@@ -0,0 +1,45 @@
+// Redeem AMM shares context
+
+use anchor_lang::prelude::*;
+use crate::state::{AmmMarket, AmmPosition, GlobalState};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(market_id: u64)]
+pub struct RedeemAmmShares<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_CLAIMS) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [AMM_MARKET_SEED, market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, AmmMarket>,
+    
+    #[account(
+        mut,
+        seeds = [AMM_POSITION_SEED, market_id.to_le_bytes().as_ref(), user.key().as_ref()],
+        bump = position.bump,
+        constraint = position.user == user.key(),
+        close = user
+    )]
+    pub position: Account<'info, AmmPosition>,
+    
+    /// CHECK: Vault PDA holding market collateral
+    #[account(
+        mut,
+        seeds = [AMM_VAULT_SEED, market_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,54 @@
+// Redeem outcome tokens context
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{GlobalState, Round};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct RedeemOutcomeTokens<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_CLAIMS) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [ROUND_SEED, round_id.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = !round.frozen @ crate::errors::SocialRouletteError::RoundFrozen
+    )]
+    pub round: Account<'info, Round>,
+    
+    #[account(
+        mut,
+        seeds = [OUTCOME_MINT_SEED, round_id.to_le_bytes().as_ref(), &[round.winning_outcome]],
+        bump
+    )]
+    pub winning_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        token::mint = winning_mint,
+        token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Vault PDA holding round funds
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, round_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
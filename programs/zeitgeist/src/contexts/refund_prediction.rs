@@ -1,15 +1,23 @@
 // Refund prediction context
 
 use anchor_lang::prelude::*;
-use crate::state::{Round, Prediction};
+use crate::state::{Balance, GlobalState, Round, Prediction, UserStats};
 use crate::constants::*;
 
 #[derive(Accounts)]
 #[instruction(round_id: u64)]
 pub struct RefundPrediction<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_REFUNDS) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
     #[account(
         seeds = [ROUND_SEED, round_id.to_le_bytes().as_ref()],
-        bump = round.bump
+        bump = round.bump,
+        constraint = !round.frozen @ crate::errors::SocialRouletteError::RoundFrozen
     )]
     pub round: Account<'info, Round>,
     
@@ -22,6 +30,13 @@ pub struct RefundPrediction<'info> {
     )]
     pub prediction: Account<'info, Prediction>,
     
+    #[account(
+        mut,
+        seeds = [USER_STATS_SEED, user.key().as_ref()],
+        bump = user_stats.bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+    
     /// CHECK: Vault PDA holding round funds
     #[account(
         mut,
@@ -33,5 +48,13 @@ pub struct RefundPrediction<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
     
+    /// Optional custodial balance to pay into instead of the wallet
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, user.key().as_ref()],
+        bump = balance.bump
+    )]
+    pub balance: Option<Account<'info, Balance>>,
+    
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
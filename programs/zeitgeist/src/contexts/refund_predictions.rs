@@ -0,0 +1,35 @@
+// Refund predictions context
+
+use anchor_lang::prelude::*;
+use crate::state::{GlobalState, Round};
+use crate::constants::*;
+
+/// Permissionless crank. Remaining accounts: `(prediction, user_stats, user)`
+/// triples for the round, where `user` is the prediction's owner
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct RefundPredictions<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_REFUNDS) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        seeds = [ROUND_SEED, round_id.to_le_bytes().as_ref()],
+        bump = round.bump,
+        constraint = !round.frozen @ crate::errors::SocialRouletteError::RoundFrozen
+    )]
+    pub round: Account<'info, Round>,
+    
+    /// CHECK: Vault PDA holding round funds
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, round_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
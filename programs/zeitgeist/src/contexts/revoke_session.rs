@@ -0,0 +1,20 @@
+// Revoke session context
+
+use anchor_lang::prelude::*;
+use crate::state::SessionToken;
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(session_key: Pubkey)]
+pub struct RevokeSession<'info> {
+    #[account(
+        mut,
+        seeds = [SESSION_TOKEN_SEED, user.key().as_ref(), session_key.as_ref()],
+        bump = session_token.bump,
+        close = user
+    )]
+    pub session_token: Account<'info, SessionToken>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
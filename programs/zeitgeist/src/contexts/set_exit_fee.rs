@@ -0,0 +1,24 @@
+// Set exit fee context
+
+use anchor_lang::prelude::*;
+use crate::state::{GlobalState, Role, Roles};
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct SetExitFee<'info> {
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Treasurer, &treasurer.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+
+    pub treasurer: Signer<'info>,
+}
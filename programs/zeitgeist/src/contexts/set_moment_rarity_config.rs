@@ -0,0 +1,24 @@
+// Set moment rarity config context
+
+use anchor_lang::prelude::*;
+use crate::state::{GlobalState, MomentConfig};
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct SetMomentRarityConfig<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = global_state.admin == admin.key() @ crate::errors::SocialRouletteError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [MOMENT_CONFIG_SEED],
+        bump = moment_config.bump
+    )]
+    pub moment_config: Account<'info, MomentConfig>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,25 @@
+// Set round frozen context
+
+use anchor_lang::prelude::*;
+use crate::state::{Role, Roles, Round};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct SetRoundFrozen<'info> {
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Pauser, &authority.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(
+        mut,
+        seeds = [ROUND_SEED, round_id.to_le_bytes().as_ref()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, Round>,
+    
+    pub authority: Signer<'info>,
+}
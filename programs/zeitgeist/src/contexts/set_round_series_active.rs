@@ -0,0 +1,25 @@
+// Set round series active context
+
+use anchor_lang::prelude::*;
+use crate::state::{Role, Roles, RoundSeries};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(series_id: u64)]
+pub struct SetRoundSeriesActive<'info> {
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::RoundCreator, &authority.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(
+        mut,
+        seeds = [ROUND_SERIES_SEED, series_id.to_le_bytes().as_ref()],
+        bump = series.bump
+    )]
+    pub series: Account<'info, RoundSeries>,
+    
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,45 @@
+// Settle AMM market context
+
+use anchor_lang::prelude::*;
+use crate::state::{AmmMarket, GlobalState, Role, Roles};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(market_id: u64)]
+pub struct SettleAmmMarket<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_SETTLEMENT) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Settler, &authority.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(
+        mut,
+        seeds = [AMM_MARKET_SEED, market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, AmmMarket>,
+    
+    /// CHECK: Oracle data source (Pyth price feed, Switchboard VRF, or on-chain data)
+    /// fixed at creation; its contents are validated based on verification_method
+    #[account(
+        address = market.data_source @ crate::errors::SocialRouletteError::InvalidOracle
+    )]
+    pub oracle_data: AccountInfo<'info>,
+    
+    /// CHECK: Oracle authority - must match market.oracle for automated settlement
+    #[account(
+        constraint = oracle.key() == market.oracle @ crate::errors::SocialRouletteError::Unauthorized
+    )]
+    pub oracle: Signer<'info>,
+    
+    pub authority: Signer<'info>,
+}
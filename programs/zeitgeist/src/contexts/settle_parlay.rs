@@ -0,0 +1,47 @@
+// Settle parlay context
+
+use anchor_lang::prelude::*;
+use crate::state::{GlobalState, Parlay, ParlayPool};
+use crate::constants::*;
+
+/// Permissionless: anyone can settle a parlay once its rounds are final.
+/// Remaining accounts: the `Round` for each leg, in leg order
+#[derive(Accounts)]
+#[instruction(parlay_id: u64)]
+pub struct SettleParlay<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_CLAIMS) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [PARLAY_POOL_SEED],
+        bump = parlay_pool.bump
+    )]
+    pub parlay_pool: Account<'info, ParlayPool>,
+    
+    #[account(
+        mut,
+        seeds = [PARLAY_SEED, user.key().as_ref(), parlay_id.to_le_bytes().as_ref()],
+        bump = parlay.bump,
+        close = user
+    )]
+    pub parlay: Account<'info, Parlay>,
+    
+    /// CHECK: Vault PDA holding the parlay bankroll and stakes
+    #[account(
+        mut,
+        seeds = [PARLAY_VAULT_SEED],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    /// Parlay owner; receives the payout and the parlay account's rent
+    #[account(mut)]
+    pub user: SystemAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
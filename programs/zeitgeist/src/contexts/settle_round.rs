@@ -1,7 +1,7 @@
 
 // Settle round context
 use anchor_lang::prelude::*;
-use crate::state::{GlobalState, Round};
+use crate::state::{GlobalState, Role, Roles, Round};
 use crate::constants::*;
 
 #[derive(Accounts)]
@@ -10,14 +10,22 @@ pub struct SettleRound<'info> {
     #[account(
         seeds = [GLOBAL_STATE_SEED],
         bump = global_state.bump,
-        constraint = global_state.admin == admin.key() @ crate::errors::SocialRouletteError::Unauthorized
+        constraint = !global_state.is_paused(PAUSE_SETTLEMENT) @ crate::errors::SocialRouletteError::ProgramPaused
     )]
     pub global_state: Account<'info, GlobalState>,
     
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Settler, &authority.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
     #[account(
         mut,
         seeds = [ROUND_SEED, round_id.to_le_bytes().as_ref()],
-        bump = round.bump
+        bump = round.bump,
+        constraint = !round.frozen @ crate::errors::SocialRouletteError::RoundFrozen
     )]
     pub round: Account<'info, Round>,
     
@@ -37,7 +45,12 @@ pub struct SettleRound<'info> {
     pub platform_wallet: AccountInfo<'info>,
     
     /// CHECK: Oracle data source (Pyth price feed, Switchboard VRF, or on-chain data)
-    /// This account is validated inside the instruction based on verification_method
+    /// This account is validated inside the instruction based on verification_method;
+    /// feeds whose contents decide the outcome must be the round's `data_source`
+    #[account(
+        constraint = !round.requires_data_source() || oracle_data.key() == round.data_source
+            @ crate::errors::SocialRouletteError::InvalidOracle
+    )]
     pub oracle_data: AccountInfo<'info>,
     
     /// CHECK: Oracle authority - must match round.oracle for automated settlement
@@ -46,7 +59,7 @@ pub struct SettleRound<'info> {
     )]
     pub oracle: Signer<'info>,
     
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
@@ -0,0 +1,47 @@
+// Spawn next round context (permissionless)
+
+use anchor_lang::prelude::*;
+use crate::state::{GlobalState, Round, RoundSeries};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(series_id: u64)]
+pub struct SpawnNextRound<'info> {
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_ROUND_CREATION) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [ROUND_SERIES_SEED, series_id.to_le_bytes().as_ref()],
+        bump = series.bump
+    )]
+    pub series: Account<'info, RoundSeries>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Round::INIT_SPACE,
+        seeds = [ROUND_SEED, series.next_round_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub round: Account<'info, Round>,
+    
+    /// CHECK: Vault PDA that will hold round funds
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, series.next_round_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    /// Anyone may crank the series and pays rent for the new round
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,29 @@
+// Submit season score context
+
+use anchor_lang::prelude::*;
+use crate::state::{Season, SeasonEntry, UserStats};
+use crate::constants::*;
+
+/// Permissionless: anyone may submit a joined player's current season score
+#[derive(Accounts)]
+#[instruction(season_id: u64, user: Pubkey)]
+pub struct SubmitSeasonScore<'info> {
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, season_id.to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+    
+    #[account(
+        seeds = [SEASON_ENTRY_SEED, season_id.to_le_bytes().as_ref(), user.as_ref()],
+        bump = season_entry.bump
+    )]
+    pub season_entry: Account<'info, SeasonEntry>,
+    
+    #[account(
+        seeds = [USER_STATS_SEED, user.as_ref()],
+        bump = user_stats.bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+}
@@ -0,0 +1,37 @@
+// Withdraw AMM liquidity context
+
+use anchor_lang::prelude::*;
+use crate::state::{AmmMarket, GlobalState};
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(market_id: u64)]
+pub struct WithdrawAmmLiquidity<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_CLAIMS) @ crate::errors::SocialRouletteError::ProgramPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+    
+    #[account(
+        mut,
+        seeds = [AMM_MARKET_SEED, market_id.to_le_bytes().as_ref()],
+        bump = market.bump,
+        constraint = market.creator == creator.key() @ crate::errors::SocialRouletteError::Unauthorized
+    )]
+    pub market: Account<'info, AmmMarket>,
+    
+    /// CHECK: Vault PDA holding market collateral
+    #[account(
+        mut,
+        seeds = [AMM_VAULT_SEED, market_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
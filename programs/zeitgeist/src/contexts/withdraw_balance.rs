@@ -0,0 +1,18 @@
+// Withdraw balance context
+
+use anchor_lang::prelude::*;
+use crate::state::Balance;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct WithdrawBalance<'info> {
+    #[account(
+        mut,
+        seeds = [BALANCE_SEED, user.key().as_ref()],
+        bump = balance.bump
+    )]
+    pub balance: Account<'info, Balance>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
@@ -0,0 +1,34 @@
+// Withdraw parlay pool context
+
+use anchor_lang::prelude::*;
+use crate::state::{ParlayPool, Role, Roles};
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct WithdrawParlayPool<'info> {
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles.bump,
+        constraint = roles.has_role(Role::Treasurer, &treasurer.key()) @ crate::errors::SocialRouletteError::MissingRole
+    )]
+    pub roles: Account<'info, Roles>,
+    
+    #[account(
+        seeds = [PARLAY_POOL_SEED],
+        bump = parlay_pool.bump
+    )]
+    pub parlay_pool: Account<'info, ParlayPool>,
+    
+    /// CHECK: Vault PDA holding the parlay bankroll and stakes
+    #[account(
+        mut,
+        seeds = [PARLAY_VAULT_SEED],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -114,4 +114,142 @@ RequiresOffChainOracle,
 #[msg("Invalid betting window duration (must be between 10 and 300 seconds)")]
 InvalidBettingWindowDuration,
 
+    #[msg("Unauthorized: signer does not hold the required role")]
+    MissingRole,
+
+    #[msg("Maximum number of role members reached")]
+    TooManyRoleMembers,
+
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+
+    #[msg("Round is frozen")]
+    RoundFrozen,
+
+    #[msg("Account is already on the latest layout version")]
+    AlreadyMigrated,
+
+    #[msg("Invalid round series id")]
+    InvalidSeriesId,
+
+    #[msg("Round series is not active")]
+    SeriesInactive,
+
+    #[msg("Previous round in the series has not ended yet")]
+    PreviousRoundNotEnded,
+
+    #[msg("Round series has spawned its maximum number of rounds")]
+    SeriesExhausted,
+
+    #[msg("Invalid exit fee")]
+    InvalidExitFee,
+
+    #[msg("Payout multiplier after this bet is below the requested minimum")]
+    SlippageExceeded,
+
+    #[msg("Not enough pool liquidity for this trade")]
+    InsufficientLiquidity,
+
+    #[msg("Not enough outcome shares held")]
+    InsufficientShares,
+
+    #[msg("Trading is closed for this market")]
+    TradingClosed,
+
+    #[msg("Outcome tokens are not enabled for every outcome of this round")]
+    OutcomeTokensNotReady,
+
+    #[msg("Outcome mints must be created in order while betting is open")]
+    InvalidOutcomeMint,
+
+    #[msg("Token account does not match the outcome mint")]
+    InvalidTokenAccount,
+
+    #[msg("Scalar lower bound must be below the upper bound")]
+    InvalidScalarBounds,
+
+    #[msg("Operation not supported for this round kind")]
+    InvalidRoundKind,
+
+    #[msg("Invalid parlay legs")]
+    InvalidParlay,
+
+    #[msg("Parlay pool cannot cover this payout")]
+    InsufficientParlayLiquidity,
+
+    #[msg("Not every round in the parlay is settled or cancelled")]
+    ParlayNotResolved,
+
+    #[msg("Payer is neither the user nor an authorized session key")]
+    SessionUnauthorized,
+
+    #[msg("Session key has expired")]
+    SessionExpired,
+
+    #[msg("Session key spend cap exceeded")]
+    SessionSpendCapExceeded,
+
+    #[msg("Invalid session duration")]
+    InvalidSessionDuration,
+
+    #[msg("Insufficient deposit balance")]
+    InsufficientBalance,
+
+    #[msg("Remaining accounts are missing or don't match")]
+    InvalidRemainingAccounts,
+
+    #[msg("Prediction has winnings; use claim_winnings")]
+    PredictionWon,
+
+    #[msg("Invalid season schedule")]
+    InvalidSeasonSchedule,
+
+    #[msg("Season is not accepting submissions")]
+    SeasonNotActive,
+
+    #[msg("Season has not ended yet")]
+    SeasonNotEnded,
+
+    #[msg("Season is already closed")]
+    SeasonClosed,
+
+    #[msg("Moment URI base is empty, too long or ends with '/'")]
+    InvalidMomentUriBase,
+
+    #[msg("Merkle tree or tree config account is invalid")]
+    InvalidMomentTree,
+
+    #[msg("Collection is not the moment card collection")]
+    InvalidMomentCollection,
+
+    #[msg("Rarity tiers must be named and get no easier as rarity rises")]
+    InvalidRarityTiers,
+
+    #[msg("Moment card is burned, mismatched or already Legendary")]
+    InvalidMomentCard,
+
+    #[msg("Win proof is not a later, unspent win by the signer")]
+    InvalidWinProof,
+
+    #[msg("This moment card's perk was already used today")]
+    PerkAlreadyUsed,
+
+    #[msg("Prediction has a moment card perk; the perk vault is required")]
+    MissingPerkVault,
+
+    #[msg("Round id is reserved for auto-assigned or series rounds")]
+    ReservedRoundId,
+
+    #[msg("Account can't be migrated in its current state")]
+    MigrationNotAllowed,
+
+    #[msg("Prediction has not been claimed or resolved yet")]
+    PredictionNotClaimed,
+
+    #[msg("Signer has no reward in this season")]
+    NoSeasonReward,
+
+    #[msg("Position already carries a moment card perk")]
+    PerkAlreadyApplied,
+
 }
@@ -1,6 +1,8 @@
 // Events emitted by the Social Roulette program
 
 use anchor_lang::prelude::*;
+use crate::constants::RARITY_TIER_COUNT;
+use crate::state::{MomentRarity, RarityTier, Role};
 
 #[event]
 pub struct GlobalStateInitialized {
@@ -19,12 +21,28 @@ pub struct RoundCreated {
     pub description: String,
 }
 
+#[event]
+pub struct ScalarRoundCreated {
+    pub round_id: u64,
+    pub lower_bound: i64,
+    pub upper_bound: i64,
+}
+
+#[event]
+pub struct ScalarRoundResolved {
+    pub round_id: u64,
+    pub resolved_value: i64,
+    pub lower_bound: i64,
+    pub upper_bound: i64,
+}
+
 #[event]
 pub struct PredictionPlaced {
     pub round_id: u64,
     pub user: Pubkey,
     pub outcome: u8,
     pub amount: u64,
+    pub position_amount: u64, // User's total stake on `outcome` after this bet
     pub timestamp: i64,
 }
 
@@ -46,6 +64,22 @@ pub struct RoundSettled {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct WinningsDistributed {
+    pub round_id: u64,
+    pub paid_count: u32,
+    pub total_paid: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PredictionsRefunded {
+    pub round_id: u64,
+    pub refunded_count: u32,
+    pub total_refunded: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct WinningsClaimed {
     pub round_id: u64,
@@ -85,6 +119,25 @@ pub struct UserStatsUpdated {
     pub total_wagered: u64,
     pub total_won: u64,
     pub win_rate: u64, // Basis points (e.g., 7500 = 75%)
+    pub total_wins: u64,
+    pub total_losses: u64,
+    pub total_refunded: u64,
+    pub pending_stake: u64,
+    pub net_profit: i64, // Realized PnL over resolved positions
+    pub current_streak: u32,
+    pub longest_streak: u32,
+    pub xp: u64,
+    pub level: u32,
+    pub achievements: u32, // ACHIEVEMENT_* bitflags
+}
+
+#[event]
+pub struct AchievementUnlocked {
+    pub user: Pubkey,
+    pub achievement: u32, // Single ACHIEVEMENT_* flag
+    pub xp: u64,
+    pub level: u32,
+    pub timestamp: i64,
 }
 
 #[event]
@@ -93,4 +146,404 @@ pub struct PredictionRefunded {
     pub user: Pubkey,
     pub amount: u64,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+#[event]
+pub struct RoleGranted {
+    pub role: Role,
+    pub member: Pubkey,
+    pub granted_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub member: Pubkey,
+    pub revoked_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformWalletUpdated {
+    pub old_wallet: Pubkey,
+    pub new_wallet: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProgramPaused {
+    pub changed_flags: u8, // Families newly paused by this call
+    pub pause_flags: u8,   // Full pause bitmask after the call
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProgramUnpaused {
+    pub changed_flags: u8, // Families newly unpaused by this call
+    pub pause_flags: u8,   // Full pause bitmask after the call
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundFrozenUpdated {
+    pub round_id: u64,
+    pub frozen: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundSeriesCreated {
+    pub series_id: u64,
+    pub creator: Pubkey,
+    pub num_outcomes: u8,
+    pub cadence: i64,
+    pub betting_window_duration: i64,
+    pub description: String,
+}
+
+#[event]
+pub struct RoundSpawned {
+    pub series_id: u64,
+    pub round_id: u64,
+    pub index: u64, // 0-based position of the round within the series
+    pub spawned_by: Pubkey,
+}
+
+#[event]
+pub struct RoundSeriesUpdated {
+    pub series_id: u64,
+    pub active: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PredictionWithdrawn {
+    pub round_id: u64,
+    pub user: Pubkey,
+    pub outcome: u8,
+    pub amount: u64,          // Stake removed from the outcome
+    pub exit_fee: u64,        // Portion of `amount` kept in the pool
+    pub remaining_stake: u64, // User's stake left on `outcome`
+    pub outcome_pool: u64,    // Total staked on `outcome` after the withdrawal
+    pub total_pool: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExitFeeUpdated {
+    pub old_exit_fee_bps: u16,
+    pub new_exit_fee_bps: u16,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AmmMarketCreated {
+    pub market_id: u64,
+    pub creator: Pubkey,
+    pub num_outcomes: u8,
+    pub initial_liquidity: u64,
+    pub trading_close_time: i64,
+    pub end_time: i64,
+    pub description: String,
+}
+
+#[event]
+pub struct AmmSharesBought {
+    pub market_id: u64,
+    pub user: Pubkey,
+    pub outcome: u8,
+    pub amount: u64,          // Total paid, including platform fee
+    pub platform_fee: u64,
+    pub shares: u64,
+    pub prices_bps: Vec<u64>, // Per-outcome prices after the trade
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AmmSharesSold {
+    pub market_id: u64,
+    pub user: Pubkey,
+    pub outcome: u8,
+    pub amount: u64,          // Collateral paid out
+    pub shares: u64,
+    pub prices_bps: Vec<u64>, // Per-outcome prices after the trade
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AmmMarketSettled {
+    pub market_id: u64,
+    pub winning_outcome: u8,
+    pub winning_shares_outstanding: u64,
+    pub creator_payout: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AmmSharesRedeemed {
+    pub market_id: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AmmLiquidityWithdrawn {
+    pub market_id: u64,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AmmMarketCancelled {
+    pub market_id: u64,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OutcomeMintCreated {
+    pub round_id: u64,
+    pub outcome: u8,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompleteSetMinted {
+    pub round_id: u64,
+    pub user: Pubkey,
+    pub amount: u64,           // Tokens minted per outcome (= lamports staked)
+    pub token_collateral: u64, // Round's token collateral after the mint
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompleteSetBurned {
+    pub round_id: u64,
+    pub user: Pubkey,
+    pub amount: u64,           // Tokens burned per outcome (= lamports returned)
+    pub token_collateral: u64, // Round's token collateral after the burn
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OutcomeTokensRedeemed {
+    pub round_id: u64,
+    pub user: Pubkey,
+    pub outcome: u8,
+    pub tokens: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParlayPoolFunded {
+    pub treasurer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParlayPoolWithdrawn {
+    pub treasurer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParlayPlaced {
+    pub parlay_id: u64,
+    pub user: Pubkey,
+    pub round_ids: Vec<u64>,
+    pub outcomes: Vec<u8>,
+    pub amount: u64,
+    pub multiplier_bps: u64,   // Combined quoted odds, an upper bound on the payout (10000 = 1x)
+    pub potential_payout: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParlaySettled {
+    pub parlay_id: u64,
+    pub user: Pubkey,
+    pub won: bool,     // No leg lost (includes full refunds when every leg is void)
+    pub void_legs: u8, // Legs dropped because their round was cancelled
+    pub payout: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SessionCreated {
+    pub user: Pubkey,
+    pub session_key: Pubkey,
+    pub expires_at: i64,
+    pub spend_cap: u64,
+    pub top_up: u64, // Lamports sent to the session key
+}
+
+#[event]
+pub struct SessionRevoked {
+    pub user: Pubkey,
+    pub session_key: Pubkey,
+    pub spent: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BalanceDeposited {
+    pub user: Pubkey,
+    pub amount: u64,
+    pub balance: u64, // Available balance after the deposit
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BalanceWithdrawn {
+    pub user: Pubkey,
+    pub amount: u64,
+    pub balance: u64, // Available balance after the withdrawal
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeasonCreated {
+    pub season_id: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub global: bool,
+    pub reward_pool: u64,
+}
+
+#[event]
+pub struct SeasonJoined {
+    pub season_id: u64,
+    pub user: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeasonScoreSubmitted {
+    pub season_id: u64,
+    pub user: Pubkey,
+    pub rank: Option<u8>, // 0-based; None if the score didn't make the leaderboard
+    pub net_profit: i64,
+    pub win_rate_bps: u64,
+    pub volume: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeasonRewardPaid {
+    pub season_id: u64,
+    pub user: Pubkey,
+    pub rank: u8,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SeasonClosed {
+    pub season_id: u64,
+    pub allocated: u64, // Owed to the final leaderboard, claimable per rank
+    pub returned: u64, // Unallocated rewards sent back to the platform wallet
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MomentCardMinted {
+    pub round_id: u64,
+    pub user: Pubkey,
+    pub moment_card: Pubkey, // Companion PDA holding the card's attributes
+    pub asset_id: Pubkey,
+    pub rarity: MomentRarity,
+    pub payout_multiplier_bps: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MomentUriBaseUpdated {
+    pub old_uri_base: String,
+    pub new_uri_base: String,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MomentTreeInitialized {
+    pub merkle_tree: Pubkey,
+    pub collection_mint: Pubkey,
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MomentRarityConfigUpdated {
+    pub rarity_tiers: [RarityTier; RARITY_TIER_COUNT],
+    pub early_bettor_window: i64,
+    pub comeback_loss_streak: u32,
+    pub largest_stake_bonus: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MomentCardsCombined {
+    pub user: Pubkey,
+    pub moment_card: Pubkey, // Reforged record of the first burned card
+    pub asset_id: Pubkey,    // Newly minted cNFT
+    pub rarity: MomentRarity,
+    pub cards_burned: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MomentCardEvolved {
+    pub user: Pubkey,
+    pub moment_card: Pubkey,
+    pub rarity: MomentRarity,
+    pub evolutions: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MomentPerkApplied {
+    pub round_id: u64,
+    pub user: Pubkey,
+    pub moment_card: Pubkey,
+    pub rarity: MomentRarity,
+    pub fee_discount_bps: u16,
+    pub boost_bps: u16,
+    pub amount: u64, // Stake the perk covers
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MomentPerkPaid {
+    pub round_id: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PerkVaultFunded {
+    pub treasurer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
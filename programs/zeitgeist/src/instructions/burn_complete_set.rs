@@ -0,0 +1,76 @@
+// Burn complete set instruction
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn};
+use crate::contexts::BurnCompleteSet;
+use crate::events::CompleteSetBurned;
+use crate::errors::SocialRouletteError;
+use crate::constants::VAULT_SEED;
+use crate::utils::validate_outcome_token_accounts;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BurnCompleteSet<'info>>,
+    round_id: u64,
+    amount: u64,
+) -> Result<()> {
+    let round = &mut ctx.accounts.round;
+    let clock = Clock::get()?;
+    
+    // A complete set is always worth exactly its stake, whatever the round's
+    // status, so this is also how tokenized stakes leave a cancelled round
+    require!(
+        round.outcome_tokens_ready(),
+        SocialRouletteError::OutcomeTokensNotReady
+    );
+    
+    require!(amount > 0, SocialRouletteError::InvalidPredictionAmount);
+    
+    validate_outcome_token_accounts(ctx.program_id, round_id, round.num_outcomes, ctx.remaining_accounts)?;
+    
+    for pair in ctx.remaining_accounts.chunks(2) {
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: pair[0].clone(),
+                    from: pair[1].clone(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+    
+    round.remove_token_collateral(amount)?;
+    
+    // Create PDA signer seeds for vault
+    let round_id_bytes = round_id.to_le_bytes();
+    let vault_seeds = &[
+        VAULT_SEED,
+        round_id_bytes.as_ref(),
+        &[ctx.bumps.vault],
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+    
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.user.to_account_info(),
+            },
+            vault_signer,
+        ),
+        amount,
+    )?;
+    
+    emit!(CompleteSetBurned {
+        round_id,
+        user: ctx.accounts.user.key(),
+        amount,
+        token_collateral: round.token_collateral,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,102 @@
+// Buy AMM shares instruction
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::contexts::BuyAmmShares;
+use crate::events::AmmSharesBought;
+use crate::errors::SocialRouletteError;
+use crate::constants::*;
+use crate::state::AmmPosition;
+use crate::utils::calculate_platform_fee;
+
+pub fn handler(
+    ctx: Context<BuyAmmShares>,
+    market_id: u64,
+    outcome: u8,
+    amount: u64,
+    min_shares_out: u64,
+) -> Result<()> {
+    let global_state = &ctx.accounts.global_state;
+    let market = &mut ctx.accounts.market;
+    let position = &mut ctx.accounts.position;
+    let clock = Clock::get()?;
+
+    require!(
+        market.is_trading(clock.unix_timestamp),
+        SocialRouletteError::TradingClosed
+    );
+
+    require!(
+        (MIN_PREDICTION_AMOUNT..=MAX_PREDICTION_AMOUNT).contains(&amount),
+        SocialRouletteError::InvalidPredictionAmount
+    );
+
+    require!(
+        outcome < market.num_outcomes,
+        SocialRouletteError::InvalidOutcome
+    );
+
+    // Platform fee is taken up front; the rest buys outcome shares
+    let platform_fee = calculate_platform_fee(amount, global_state.platform_fee_bps)?;
+    let invested = amount
+        .checked_sub(platform_fee)
+        .ok_or(SocialRouletteError::ArithmeticUnderflow)?;
+
+    let shares = market.calc_buy_shares(outcome, invested)?;
+    require!(
+        shares >= min_shares_out,
+        SocialRouletteError::SlippageExceeded
+    );
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        invested,
+    )?;
+
+    if platform_fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: ctx.accounts.platform_wallet.to_account_info(),
+                },
+            ),
+            platform_fee,
+        )?;
+    }
+
+    market.apply_buy(outcome, invested, shares)?;
+
+    // Initialize position on the first buy (init_if_needed handles account creation)
+    if position.user == Pubkey::default() {
+        position.market_id = market_id;
+        position.user = ctx.accounts.user.key();
+        position.shares = [0; MAX_OUTCOMES as usize];
+        position.cost_basis = 0;
+        position.bump = ctx.bumps.position;
+        position.version = AmmPosition::VERSION;
+        position.reserved = [0; 24];
+    }
+    position.add_shares(outcome, shares)?;
+    position.add_cost(invested)?;
+
+    emit!(AmmSharesBought {
+        market_id,
+        user: ctx.accounts.user.key(),
+        outcome,
+        amount,
+        platform_fee,
+        shares,
+        prices_bps: market.prices_bps()?,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+// Cancel AMM market instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::CancelAmmMarket;
+use crate::events::AmmMarketCancelled;
+use crate::errors::SocialRouletteError;
+use crate::state::Role;
+
+pub fn handler(ctx: Context<CancelAmmMarket>, market_id: u64, reason: String) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    // A market nobody settled in time must not lock its collateral forever.
+    // Refunds return each trader's cost basis, so cancelling moves no value
+    // between traders and whoever cancels gains nothing.
+    require!(
+        ctx.accounts.roles.has_role(Role::Settler, &ctx.accounts.authority.key())
+            || market.settlement_expired(clock.unix_timestamp),
+        SocialRouletteError::MissingRole
+    );
+
+    market.cancel()?;
+
+    emit!(AmmMarketCancelled {
+        market_id,
+        reason,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+// Claim season reward instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::ClaimSeasonReward;
+use crate::events::SeasonRewardPaid;
+use crate::errors::SocialRouletteError;
+use crate::constants::SEASON_VAULT_SEED;
+
+pub fn handler(ctx: Context<ClaimSeasonReward>, season_id: u64) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    
+    require!(season.closed, SocialRouletteError::SeasonNotEnded);
+    
+    let (rank, reward) = season.claim_reward(&ctx.accounts.user.key())?;
+    
+    if reward > 0 {
+        // Create PDA signer seeds for vault
+        let season_id_bytes = season_id.to_le_bytes();
+        let vault_seeds = &[
+            SEASON_VAULT_SEED,
+            season_id_bytes.as_ref(),
+            &[ctx.bumps.vault],
+        ];
+        let vault_signer = &[&vault_seeds[..]];
+        
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.user.to_account_info(),
+                },
+                vault_signer,
+            ),
+            reward,
+        )?;
+    }
+    
+    emit!(SeasonRewardPaid {
+        season_id,
+        user: ctx.accounts.user.key(),
+        rank,
+        amount: reward,
+    });
+    
+    Ok(())
+}
@@ -2,9 +2,10 @@
 
 use anchor_lang::prelude::*;
 use crate::contexts::ClaimWinnings;
-use crate::events::WinningsClaimed;
+use crate::events::{MomentPerkPaid, WinningsClaimed};
 use crate::errors::SocialRouletteError;
-use crate::utils::calculate_winnings;
+use crate::moment::pay_perk_bonus;
+use crate::utils::{calculate_perk_bonus, calculate_prediction_winnings};
 
 pub fn handler(ctx: Context<ClaimWinnings>, round_id: u64) -> Result<()> {
     let round = &ctx.accounts.round;
@@ -24,21 +25,8 @@ pub fn handler(ctx: Context<ClaimWinnings>, round_id: u64) -> Result<()> {
         SocialRouletteError::AlreadyClaimed
     );
     
-    // Validate user is a winner
-    require!(
-        prediction.is_winner(round.winning_outcome),
-        SocialRouletteError::NoWinnings
-    );
-    
-    // Calculate winnings
-    let winnings = calculate_winnings(
-        prediction.amount,
-        round.winning_pool,
-        round.total_pool,
-        
-        round.platform_fee_collected,
-        
-    )?;
+    // Calculate winnings (0 for a position with nothing on the winning side)
+    let winnings = calculate_prediction_winnings(round, prediction)?;
 //     round.leaderboard.push((ctx.accounts.user.key(), winnings));
 // round.leaderboard.sort_by(|a, b| b.1.cmp(&a.1)); // Sort by winnings desc
     
@@ -60,24 +48,69 @@ pub fn handler(ctx: Context<ClaimWinnings>, round_id: u64) -> Result<()> {
     ];
     let vault_signer = &[&vault_seeds[..]];
 
+    // Pay into the custodial balance if given (ready to bet again), else the wallet
+    let recipient = match ctx.accounts.balance.as_mut() {
+        Some(balance) => {
+            balance.credit(winnings)?;
+            balance.to_account_info()
+        }
+        None => ctx.accounts.user.to_account_info(),
+    };
+
     // CPI to System Program to transfer from vault to user
     anchor_lang::system_program::transfer(
         CpiContext::new_with_signer(
             ctx.accounts.system_program.to_account_info(),
             anchor_lang::system_program::Transfer {
                 from: ctx.accounts.vault.to_account_info(),
-                to: ctx.accounts.user.to_account_info(),
+                to: recipient.clone(),
             },
             vault_signer,
         ),
         winnings,
     )?;
     
+    // Moment card perk: fee rebate and boost, paid by the perk vault while it lasts
+    let bonus = calculate_perk_bonus(
+        prediction.perk_winnings(winnings),
+        round.total_pool,
+        round.platform_fee_collected,
+        prediction.perk_fee_discount_bps,
+        prediction.perk_boost_bps,
+    )?;
+    let mut perk_paid = 0;
+    if bonus > 0 {
+        let perk_vault = ctx.accounts.perk_vault
+            .as_ref()
+            .ok_or(SocialRouletteError::MissingPerkVault)?;
+        perk_paid = pay_perk_bonus(
+            &perk_vault.to_account_info(),
+            ctx.bumps.perk_vault.ok_or(SocialRouletteError::MissingPerkVault)?,
+            recipient,
+            &ctx.accounts.system_program.to_account_info(),
+            bonus,
+        )?;
+        if let Some(balance) = ctx.accounts.balance.as_mut() {
+            balance.credit(perk_paid)?;
+        }
+        emit!(MomentPerkPaid {
+            round_id,
+            user: ctx.accounts.user.key(),
+            amount: perk_paid,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    let total_paid = winnings
+        .checked_add(perk_paid)
+        .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+    
     // Mark prediction as claimed
     prediction.mark_claimed()?;
     
     // Update user stats
-    user_stats.record_win(winnings)?;
+    let unlocked = user_stats.record_win(prediction.amount, total_paid)?;
+    emit!(user_stats.updated_event());
+    user_stats.emit_unlocked(unlocked, clock.unix_timestamp);
     
     emit!(WinningsClaimed {
         round_id,
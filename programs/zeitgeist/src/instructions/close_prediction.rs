@@ -0,0 +1,9 @@
+// Close prediction instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::ClosePrediction;
+
+pub fn handler(_ctx: Context<ClosePrediction>, _round_id: u64) -> Result<()> {
+    // Note: Account closure handled automatically by `close = user` in context
+    Ok(())
+}
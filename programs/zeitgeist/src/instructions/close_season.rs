@@ -0,0 +1,58 @@
+// Close season instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::CloseSeason;
+use crate::events::SeasonClosed;
+use crate::errors::SocialRouletteError;
+use crate::constants::SEASON_VAULT_SEED;
+
+pub fn handler(ctx: Context<CloseSeason>, season_id: u64) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let clock = Clock::get()?;
+    
+    require!(!season.closed, SocialRouletteError::SeasonClosed);
+    require!(clock.unix_timestamp >= season.end_time, SocialRouletteError::SeasonNotEnded);
+    
+    // Create PDA signer seeds for vault
+    let season_id_bytes = season_id.to_le_bytes();
+    let vault_seeds = &[
+        SEASON_VAULT_SEED,
+        season_id_bytes.as_ref(),
+        &[ctx.bumps.vault],
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+    
+    // The final board's rewards stay in the vault until each player claims,
+    // so one wallet that can't receive lamports doesn't hold up the rest
+    let allocated = season.allocated_rewards()?;
+    
+    // Shares of unfilled ranks and rounding dust go back to the treasury
+    let returned = season.reward_pool
+        .checked_sub(allocated)
+        .ok_or(SocialRouletteError::ArithmeticUnderflow)?;
+    
+    if returned > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.platform_wallet.to_account_info(),
+                },
+                vault_signer,
+            ),
+            returned,
+        )?;
+    }
+    
+    season.closed = true;
+    
+    emit!(SeasonClosed {
+        season_id,
+        allocated,
+        returned,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
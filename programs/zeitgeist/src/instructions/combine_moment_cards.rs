@@ -0,0 +1,123 @@
+// Combine moment cards instruction
+
+use anchor_lang::prelude::*;
+use crate::constants::MOMENT_COMBINE_COUNT;
+use crate::contexts::CombineMomentCards;
+use crate::errors::SocialRouletteError;
+use crate::events::MomentCardsCombined;
+use crate::moment::{burn_card, mint_card, MomentCollection, MomentTree};
+use crate::state::{MomentCard, MomentRarity};
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CombineMomentCards<'info>>,
+    roots: Vec<[u8; 32]>,
+    proof_len: u8,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let stride = 3 + proof_len as usize;
+    
+    require!(
+        roots.len() == MOMENT_COMBINE_COUNT
+            && ctx.remaining_accounts.len() == MOMENT_COMBINE_COUNT * stride,
+        SocialRouletteError::InvalidRemainingAccounts
+    );
+    
+    let mut forged: Option<Account<'info, MomentCard>> = None;
+    let mut tier: Option<MomentRarity> = None;
+    let mut burned_keys: Vec<Pubkey> = Vec::with_capacity(MOMENT_COMBINE_COUNT);
+    
+    for (accounts, root) in ctx.remaining_accounts.chunks(stride).zip(roots) {
+        let (card_info, merkle_tree, tree_authority, proof) =
+            (&accounts[0], &accounts[1], &accounts[2], &accounts[3..]);
+        
+        let mut card = Account::<MomentCard>::try_from(card_info)?;
+        let rarity = *tier.get_or_insert(card.rarity);
+        
+        // Every input is a distinct live card of the same, upgradable tier
+        require!(
+            !card.burned
+                && card.rarity == rarity
+                && rarity != MomentRarity::Legendary
+                && !burned_keys.contains(&card_info.key()),
+            SocialRouletteError::InvalidMomentCard
+        );
+        require_keys_eq!(
+            merkle_tree.key(),
+            card.merkle_tree,
+            SocialRouletteError::InvalidMomentTree
+        );
+        
+        // Bubblegum verifies the proof and that the signer holds the card
+        burn_card(
+            &MomentTree {
+                bubblegum_program: &ctx.accounts.bubblegum_program,
+                tree_authority,
+                merkle_tree,
+                log_wrapper: &ctx.accounts.log_wrapper,
+                compression_program: &ctx.accounts.compression_program,
+                system_program: &ctx.accounts.system_program,
+            },
+            &ctx.accounts.user,
+            &card,
+            root,
+            proof,
+        )?;
+        
+        card.burned = true;
+        burned_keys.push(card_info.key());
+        
+        if forged.is_none() {
+            forged = Some(card);
+        } else {
+            card.exit(ctx.program_id)?;
+        }
+    }
+    
+    let (mut card, rarity) = forged
+        .zip(tier)
+        .ok_or(SocialRouletteError::InvalidRemainingAccounts)?;
+    
+    // The first card's record is reforged one tier up and pointed at the new cNFT
+    let minted = mint_card(
+        &MomentTree {
+            bubblegum_program: &ctx.accounts.bubblegum_program,
+            tree_authority: &ctx.accounts.tree_authority,
+            merkle_tree: &ctx.accounts.merkle_tree,
+            log_wrapper: &ctx.accounts.log_wrapper,
+            compression_program: &ctx.accounts.compression_program,
+            system_program: &ctx.accounts.system_program,
+        },
+        &MomentCollection {
+            moment_authority: &ctx.accounts.moment_authority,
+            authority_bump: ctx.accounts.moment_config.authority_bump,
+            collection_mint: &ctx.accounts.collection_mint,
+            collection_metadata: &ctx.accounts.collection_metadata,
+            collection_edition: &ctx.accounts.collection_edition,
+            bubblegum_signer: &ctx.accounts.bubblegum_signer,
+            token_metadata_program: &ctx.accounts.token_metadata_program,
+        },
+        &ctx.accounts.user,
+        card.key(),
+        card.round_id,
+        &ctx.accounts.global_state.moment_uri_base,
+    )?;
+    
+    card.set_leaf(ctx.accounts.merkle_tree.key(), &minted);
+    card.rarity = rarity.upgraded(1);
+    card.exit(ctx.program_id)?;
+    
+    let moment_upgrades = &mut ctx.accounts.moment_upgrades;
+    moment_upgrades.init_if_new(ctx.accounts.user.key(), ctx.bumps.moment_upgrades);
+    moment_upgrades.record_combine(MOMENT_COMBINE_COUNT as u32, clock.unix_timestamp);
+    
+    emit!(MomentCardsCombined {
+        user: ctx.accounts.user.key(),
+        moment_card: card.key(),
+        asset_id: card.asset_id,
+        rarity: card.rarity,
+        cards_burned: MOMENT_COMBINE_COUNT as u8,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
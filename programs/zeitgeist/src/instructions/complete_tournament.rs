@@ -0,0 +1,30 @@
+// Complete tournament instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::CompleteTournament;
+use crate::events::TournamentEnded;
+use crate::errors::SocialRouletteError;
+
+pub fn handler(ctx: Context<CompleteTournament>, tournament_id: u64, winner: Pubkey) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let winner_stats = &mut ctx.accounts.winner_stats;
+    let clock = Clock::get()?;
+    
+    require!(!tournament.is_completed(), SocialRouletteError::TournamentEnded);
+    
+    tournament.complete(winner)?;
+    
+    let unlocked = winner_stats.record_tournament_win();
+    
+    emit!(TournamentEnded {
+        tournament_id,
+        winner,
+        total_prize_pool: tournament.prize_pool,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    emit!(winner_stats.updated_event());
+    winner_stats.emit_unlocked(unlocked, clock.unix_timestamp);
+    
+    Ok(())
+}
@@ -0,0 +1,110 @@
+// Create AMM market instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::CreateAmmMarket;
+use crate::events::AmmMarketCreated;
+use crate::errors::SocialRouletteError;
+use crate::constants::*;
+use crate::state::{AmmMarket, AmmStatus, VerificationMethod};
+use crate::utils::{validate_betting_duration, validate_future_timestamp};
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateAmmMarket>,
+    market_id: u64,
+    description: String,
+    num_outcomes: u8,
+    verification_method: VerificationMethod,
+    target_value: i64,
+    data_source: Pubkey,
+    oracle: Pubkey,
+    trading_close_time: i64,
+    end_time: i64,
+    initial_liquidity: u64,
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    // Validate inputs
+    require!(
+        (2..=MAX_OUTCOMES).contains(&num_outcomes),
+        SocialRouletteError::InvalidOutcomeCount
+    );
+
+    // AMM markets only settle through the oracle, so the method must resolve on-chain
+    require!(
+        verification_method.is_oracle_resolvable(),
+        SocialRouletteError::RequiresOffChainOracle
+    );
+
+    validate_future_timestamp(trading_close_time, clock.unix_timestamp)?;
+    validate_betting_duration(clock.unix_timestamp, end_time, MIN_BETTING_DURATION, MAX_BETTING_DURATION)?;
+    require!(
+        trading_close_time <= end_time,
+        SocialRouletteError::InvalidBettingDuration
+    );
+
+    require!(
+        initial_liquidity >= MIN_AMM_LIQUIDITY,
+        SocialRouletteError::InsufficientLiquidity
+    );
+
+    // Initialize vault with rent-exempt minimum plus the creator's liquidity
+    let rent = Rent::get()?;
+    let vault_rent_exempt = if ctx.accounts.vault.lamports() == 0 {
+        rent.minimum_balance(0)
+    } else {
+        0
+    };
+
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        initial_liquidity
+            .checked_add(vault_rent_exempt)
+            .ok_or(SocialRouletteError::ArithmeticOverflow)?,
+    )?;
+
+    // Liquidity mints one complete set per lamport, all held by the pool
+    let mut reserves = [0; MAX_OUTCOMES as usize];
+    reserves[..num_outcomes as usize].fill(initial_liquidity);
+
+    // Initialize all market fields explicitly
+    market.market_id = market_id;
+    market.creator = ctx.accounts.creator.key();
+    market.question = description.clone();
+    market.num_outcomes = num_outcomes;
+    market.verification_method = verification_method;
+    market.target_value = target_value;
+    market.data_source = data_source;
+    market.oracle = oracle;
+    market.trading_close_time = trading_close_time;
+    market.end_time = end_time;
+    market.reserves = reserves;
+    market.outstanding_shares = [0; MAX_OUTCOMES as usize];
+    market.collateral = initial_liquidity;
+    market.trader_collateral = 0;
+    market.winning_outcome = crate::state::Round::UNSET_OUTCOME;
+    market.status = AmmStatus::Trading;
+    market.liquidity_withdrawn = false;
+    market.bump = ctx.bumps.market;
+    market.version = AmmMarket::VERSION;
+    market.reserved = [0; 56];
+
+    emit!(AmmMarketCreated {
+        market_id,
+        creator: market.creator,
+        num_outcomes,
+        initial_liquidity,
+        trading_close_time,
+        end_time,
+        description,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+// Create outcome mint instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::CreateOutcomeMint;
+use crate::events::OutcomeMintCreated;
+use crate::errors::SocialRouletteError;
+use crate::state::{RoundKind, RoundStatus};
+
+pub fn handler(ctx: Context<CreateOutcomeMint>, round_id: u64, outcome: u8) -> Result<()> {
+    let round = &mut ctx.accounts.round;
+    let clock = Clock::get()?;
+    
+    // Mints can only be added while the round still accepts bets
+    require!(
+        round.status == RoundStatus::Active && clock.unix_timestamp < round.betting_close_time,
+        SocialRouletteError::BettingEnded
+    );
+    
+    // Scalar payouts aren't all-or-nothing per outcome, so they can't back a winning token
+    require!(
+        round.kind == RoundKind::Categorical,
+        SocialRouletteError::InvalidRoundKind
+    );
+    
+    // Create mints in outcome order so the count doubles as the readiness check
+    require!(
+        outcome == round.outcome_mints_created && outcome < round.num_outcomes,
+        SocialRouletteError::InvalidOutcomeMint
+    );
+    
+    round.outcome_mints_created = round.outcome_mints_created
+        .checked_add(1)
+        .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+    
+    emit!(OutcomeMintCreated {
+        round_id,
+        outcome,
+        mint: ctx.accounts.outcome_mint.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -3,12 +3,13 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::pubkey::Pubkey;
 use crate::contexts::CreateRound;
-use crate::state::RoundStatus;
+use crate::state::{Round, RoundStatus};
 use crate::events::RoundCreated;
 use crate::errors::SocialRouletteError;
 use crate::constants::*;
-use crate::utils::{validate_betting_duration, validate_future_timestamp};
+use crate::utils::validate_round_schedule;
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
    ctx: Context<CreateRound>,
     round_id: u64,
@@ -26,24 +27,20 @@ pub fn handler(
     let round = &mut ctx.accounts.round;
     let clock = Clock::get()?;
     
-    // Validate inputs
+    // Higher ids belong to create_round_auto and round series
     require!(
-        num_outcomes >= 2 && num_outcomes <= MAX_OUTCOMES,
-        SocialRouletteError::InvalidOutcomeCount
+        Round::is_manual_round_id(round_id),
+        SocialRouletteError::ReservedRoundId
     );
     
-    validate_future_timestamp(start_time, clock.unix_timestamp)?;
-    validate_betting_duration(start_time, end_time, MIN_BETTING_DURATION, MAX_BETTING_DURATION)?;
-    require!(
-    betting_window_duration >= 10 && betting_window_duration <= 300,
-    SocialRouletteError::InvalidBettingWindowDuration
-);
-
-
-      // Calculate betting close time (10 seconds after start)
-    let betting_close_time = start_time
-        .checked_add(betting_window_duration)
-        .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+    // Validate inputs and calculate betting close time
+    let betting_close_time = validate_round_schedule(
+        start_time,
+        end_time,
+        num_outcomes,
+        betting_window_duration,
+        clock.unix_timestamp,
+    )?;
     
     // Initialize all round fields explicitly
     round.round_id = round_id;
@@ -65,6 +62,17 @@ round.verification_method = verification_method;  // ← ADD
 round.target_value = target_value;                // ← ADD
 round.data_source = data_source;                  // ← ADD
 round.oracle = oracle;                            // ← ADD
+round.frozen = false;
+round.version = crate::state::Round::VERSION;
+round.outcome_pools = [0; MAX_OUTCOMES as usize];
+round.outcome_mints_created = 0;
+round.token_collateral = 0;
+round.kind = crate::state::RoundKind::Categorical;
+round.lower_bound = 0;
+round.upper_bound = 0;
+round.resolved_value = 0;
+round.largest_stake = 0;
+round.reserved = [0; 22];
     // Manually initialize vault by transferring rent-exempt minimum
 // Manually derive vault PDA and verify
 let (vault_pda, _vault_bump) = Pubkey::find_program_address(
@@ -0,0 +1,109 @@
+// Create round with a program-assigned id instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::CreateRoundAuto;
+use crate::state::{Round, RoundKind, RoundLookup, RoundStatus, VerificationMethod};
+use crate::events::RoundCreated;
+use crate::utils::validate_round_schedule;
+use crate::constants::MAX_OUTCOMES;
+
+/// Returns the assigned round id
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateRoundAuto>,
+    client_nonce: u64,
+    start_time: i64,
+    end_time: i64,
+    num_outcomes: u8,
+    description: String,
+    verification_method: VerificationMethod,
+    target_value: i64,
+    data_source: Pubkey,
+    oracle: Pubkey,
+    betting_window_duration: i64,
+) -> Result<u64> {
+    let global_state = &mut ctx.accounts.global_state;
+    let round = &mut ctx.accounts.round;
+    let round_lookup = &mut ctx.accounts.round_lookup;
+    let clock = Clock::get()?;
+
+    // Validate inputs and calculate betting close time
+    let betting_close_time = validate_round_schedule(
+        start_time,
+        end_time,
+        num_outcomes,
+        betting_window_duration,
+        clock.unix_timestamp,
+    )?;
+
+    let round_id = global_state.next_auto_round_id()?;
+
+    // Initialize all round fields explicitly
+    round.round_id = round_id;
+    round.creator = ctx.accounts.creator.key();
+    round.start_time = start_time;
+    round.betting_close_time = betting_close_time;
+    round.end_time = end_time;
+    round.total_pool = 0;
+    round.total_predictions = 0;
+    round.platform_fee_collected = 0;
+    round.num_outcomes = num_outcomes;
+    round.winning_outcome = Round::UNSET_OUTCOME;
+    round.tournament = None;
+    round.winning_pool = 0;
+    round.status = RoundStatus::Active;
+    round.bump = ctx.bumps.round;
+    round.question = description.clone();
+    round.verification_method = verification_method;
+    round.target_value = target_value;
+    round.data_source = data_source;
+    round.oracle = oracle;
+    round.frozen = false;
+    round.version = Round::VERSION;
+    round.outcome_pools = [0; MAX_OUTCOMES as usize];
+    round.outcome_mints_created = 0;
+    round.token_collateral = 0;
+    round.kind = RoundKind::Categorical;
+    round.lower_bound = 0;
+    round.upper_bound = 0;
+    round.resolved_value = 0;
+    round.largest_stake = 0;
+    round.reserved = [0; 22];
+
+    // Record creator + nonce -> id so clients can find their round idempotently
+    round_lookup.creator = ctx.accounts.creator.key();
+    round_lookup.client_nonce = client_nonce;
+    round_lookup.round_id = round_id;
+    round_lookup.bump = ctx.bumps.round_lookup;
+    round_lookup.version = RoundLookup::VERSION;
+    round_lookup.reserved = [0; 32];
+
+    // Initialize vault by transferring rent-exempt minimum
+    let rent = Rent::get()?;
+    if ctx.accounts.vault.lamports() == 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            rent.minimum_balance(0),
+        )?;
+    }
+
+    // Update global state
+    global_state.increment_rounds()?;
+
+    emit!(RoundCreated {
+        round_id,
+        creator: ctx.accounts.creator.key(),
+        start_time,
+        end_time,
+        num_outcomes,
+        description,
+    });
+
+    Ok(round_id)
+}
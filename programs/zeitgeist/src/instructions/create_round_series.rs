@@ -0,0 +1,69 @@
+// Create round series instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::CreateRoundSeries;
+use crate::events::RoundSeriesCreated;
+use crate::errors::SocialRouletteError;
+use crate::constants::*;
+use crate::state::{RoundSeries, VerificationMethod};
+use crate::utils::validate_betting_duration;
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateRoundSeries>,
+    series_id: u64,
+    description: String,
+    num_outcomes: u8,
+    verification_method: VerificationMethod,
+    target_value: i64,
+    data_source: Pubkey,
+    oracle: Pubkey,
+    cadence: i64,
+    betting_window_duration: i64,
+) -> Result<()> {
+    let series = &mut ctx.accounts.series;
+
+    // Validate template against the same limits as create_round
+    require!(
+        (2..=MAX_OUTCOMES).contains(&num_outcomes),
+        SocialRouletteError::InvalidOutcomeCount
+    );
+
+    validate_betting_duration(0, cadence, MIN_BETTING_DURATION, MAX_BETTING_DURATION)?;
+
+    require!(
+        (MIN_BETTING_WINDOW_DURATION..=MAX_BETTING_WINDOW_DURATION).contains(&betting_window_duration)
+            && betting_window_duration <= cadence,
+        SocialRouletteError::InvalidBettingWindowDuration
+    );
+
+    // Initialize all series fields explicitly
+    series.series_id = series_id;
+    series.creator = ctx.accounts.creator.key();
+    series.question = description.clone();
+    series.num_outcomes = num_outcomes;
+    series.verification_method = verification_method;
+    series.target_value = target_value;
+    series.data_source = data_source;
+    series.oracle = oracle;
+    series.cadence = cadence;
+    series.betting_window_duration = betting_window_duration;
+    series.next_round_id = RoundSeries::first_round_id(series_id)?;
+    series.rounds_spawned = 0;
+    series.last_end_time = 0;
+    series.active = true;
+    series.bump = ctx.bumps.series;
+    series.version = RoundSeries::VERSION;
+    series.reserved = [0; 64];
+
+    emit!(RoundSeriesCreated {
+        series_id,
+        creator: series.creator,
+        num_outcomes,
+        cadence,
+        betting_window_duration,
+        description,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+// Create scalar round instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::CreateRound;
+use crate::state::{Round, RoundKind, RoundStatus, VerificationMethod};
+use crate::events::{RoundCreated, ScalarRoundCreated};
+use crate::errors::SocialRouletteError;
+use crate::utils::validate_round_schedule;
+use crate::constants::MAX_OUTCOMES;
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateRound>,
+    round_id: u64,
+    start_time: i64,
+    end_time: i64,
+    description: String,
+    data_source: Pubkey,
+    oracle: Pubkey,
+    betting_window_duration: i64,
+    lower_bound: i64,
+    upper_bound: i64,
+) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    let round = &mut ctx.accounts.round;
+    let clock = Clock::get()?;
+
+    // Scalar rounds have exactly two pools: short (0) and long (1)
+    let num_outcomes = 2;
+
+    // Higher ids belong to create_round_auto and round series
+    require!(
+        Round::is_manual_round_id(round_id),
+        SocialRouletteError::ReservedRoundId
+    );
+
+    // Validate inputs and calculate betting close time
+    let betting_close_time = validate_round_schedule(
+        start_time,
+        end_time,
+        num_outcomes,
+        betting_window_duration,
+        clock.unix_timestamp,
+    )?;
+
+    require!(
+        lower_bound < upper_bound,
+        SocialRouletteError::InvalidScalarBounds
+    );
+
+    // Initialize all round fields explicitly
+    round.round_id = round_id;
+    round.creator = ctx.accounts.creator.key();
+    round.start_time = start_time;
+    round.betting_close_time = betting_close_time;
+    round.end_time = end_time;
+    round.total_pool = 0;
+    round.total_predictions = 0;
+    round.platform_fee_collected = 0;
+    round.num_outcomes = num_outcomes;
+    round.winning_outcome = Round::UNSET_OUTCOME;
+    round.tournament = None;
+    round.winning_pool = 0;
+    round.status = RoundStatus::Active;
+    round.bump = ctx.bumps.round;
+    round.question = description.clone();
+    round.verification_method = VerificationMethod::PythPrice;
+    round.target_value = 0;
+    round.data_source = data_source;
+    round.oracle = oracle;
+    round.frozen = false;
+    round.version = Round::VERSION;
+    round.outcome_pools = [0; MAX_OUTCOMES as usize];
+    round.outcome_mints_created = 0;
+    round.token_collateral = 0;
+    round.kind = RoundKind::Scalar;
+    round.lower_bound = lower_bound;
+    round.upper_bound = upper_bound;
+    round.resolved_value = 0;
+    round.largest_stake = 0;
+    round.reserved = [0; 22];
+
+    // Initialize vault by transferring rent-exempt minimum
+    let rent = Rent::get()?;
+    if ctx.accounts.vault.lamports() == 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            rent.minimum_balance(0),
+        )?;
+    }
+
+    // Update global state
+    global_state.increment_rounds()?;
+
+    emit!(RoundCreated {
+        round_id,
+        creator: ctx.accounts.creator.key(),
+        start_time,
+        end_time,
+        num_outcomes,
+        description,
+    });
+
+    emit!(ScalarRoundCreated {
+        round_id,
+        lower_bound,
+        upper_bound,
+    });
+
+    Ok(())
+}
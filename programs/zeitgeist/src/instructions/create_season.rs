@@ -0,0 +1,68 @@
+// Create season instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::CreateSeason;
+use crate::events::SeasonCreated;
+use crate::errors::SocialRouletteError;
+use crate::state::Season;
+
+pub fn handler(
+    ctx: Context<CreateSeason>,
+    season_id: u64,
+    start_time: i64,
+    end_time: i64,
+    global: bool,
+    reward_pool: u64,
+) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let clock = Clock::get()?;
+    
+    require!(
+        start_time < end_time && end_time > clock.unix_timestamp,
+        SocialRouletteError::InvalidSeasonSchedule
+    );
+    
+    season.season_id = season_id;
+    season.start_time = start_time;
+    season.end_time = end_time;
+    season.global = global;
+    season.reward_pool = reward_pool;
+    season.closed = false;
+    season.leaderboard = Vec::new();
+    season.bump = ctx.bumps.season;
+    season.version = Season::VERSION;
+    season.rewards_claimed = 0;
+    season.reserved = [0; 30];
+    
+    // Allocate the rewards up front so closing the season can't come up short
+    if reward_pool > 0 {
+        let vault_rent_exempt = if ctx.accounts.vault.lamports() == 0 {
+            Rent::get()?.minimum_balance(0)
+        } else {
+            0
+        };
+        
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.treasurer.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            reward_pool
+                .checked_add(vault_rent_exempt)
+                .ok_or(SocialRouletteError::ArithmeticOverflow)?,
+        )?;
+    }
+    
+    emit!(SeasonCreated {
+        season_id,
+        start_time,
+        end_time,
+        global,
+        reward_pool,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,66 @@
+// Create session instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::CreateSession;
+use crate::events::SessionCreated;
+use crate::errors::SocialRouletteError;
+use crate::constants::*;
+use crate::state::SessionToken;
+
+pub fn handler(
+    ctx: Context<CreateSession>,
+    session_key: Pubkey,
+    duration: i64,
+    spend_cap: u64,
+    top_up: u64,
+) -> Result<()> {
+    let session_token = &mut ctx.accounts.session_token;
+    let clock = Clock::get()?;
+    
+    require!(
+        duration > 0 && duration <= MAX_SESSION_DURATION,
+        SocialRouletteError::InvalidSessionDuration
+    );
+    
+    require!(
+        session_key != ctx.accounts.user.key(),
+        SocialRouletteError::SessionUnauthorized
+    );
+    
+    let expires_at = clock.unix_timestamp
+        .checked_add(duration)
+        .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+    
+    session_token.user = ctx.accounts.user.key();
+    session_token.session_key = session_key;
+    session_token.expires_at = expires_at;
+    session_token.spend_cap = spend_cap;
+    session_token.spent = 0;
+    session_token.bump = ctx.bumps.session_token;
+    session_token.version = SessionToken::VERSION;
+    session_token.reserved = [0; 32];
+    
+    // Session keys pay stakes and fees themselves; fund them in the same signature
+    if top_up > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: ctx.accounts.session_signer.to_account_info(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    
+    emit!(SessionCreated {
+        user: ctx.accounts.user.key(),
+        session_key,
+        expires_at,
+        spend_cap,
+        top_up,
+    });
+    
+    Ok(())
+}
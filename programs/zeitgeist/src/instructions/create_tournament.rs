@@ -46,6 +46,8 @@ pub fn handler(
     tournament.participant_count = 0;
     tournament.status = TournamentStatus::Pending;
     tournament.bump = ctx.bumps.tournament;
+    tournament.version = crate::state::Tournament::VERSION;
+    tournament.reserved = [0; 64];
     
     // Update global state
     global_state.increment_tournaments()?;
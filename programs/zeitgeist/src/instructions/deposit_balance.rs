@@ -0,0 +1,51 @@
+// Deposit balance instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::DepositBalance;
+use crate::events::BalanceDeposited;
+use crate::errors::SocialRouletteError;
+use crate::state::Balance;
+
+pub fn handler(ctx: Context<DepositBalance>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    require!(amount > 0, SocialRouletteError::InvalidPredictionAmount);
+    
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.balance.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let balance = &mut ctx.accounts.balance;
+    
+    // Initialize on first deposit (init_if_needed handles account creation)
+    if balance.user == Pubkey::default() {
+        balance.user = ctx.accounts.user.key();
+        balance.amount = 0;
+        balance.total_deposited = 0;
+        balance.total_withdrawn = 0;
+        balance.bump = ctx.bumps.balance;
+        balance.version = Balance::VERSION;
+        balance.reserved = [0; 32];
+    }
+    
+    balance.credit(amount)?;
+    balance.total_deposited = balance.total_deposited
+        .checked_add(amount)
+        .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+    
+    emit!(BalanceDeposited {
+        user: balance.user,
+        amount,
+        balance: balance.amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,158 @@
+// Distribute winnings instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::DistributeWinnings;
+use crate::events::{MomentPerkPaid, WinningsClaimed, WinningsDistributed};
+use crate::errors::SocialRouletteError;
+use crate::constants::VAULT_SEED;
+use crate::state::{Prediction, UserStats};
+use crate::moment::pay_perk_bonus;
+use crate::utils::{calculate_perk_bonus, calculate_prediction_winnings};
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeWinnings<'info>>,
+    round_id: u64,
+) -> Result<()> {
+    let round = &ctx.accounts.round;
+    let clock = Clock::get()?;
+    
+    require!(round.is_settled(), SocialRouletteError::RoundNotSettled);
+    
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len().is_multiple_of(3),
+        SocialRouletteError::InvalidRemainingAccounts
+    );
+    
+    // Create PDA signer seeds for vault
+    let round_id_bytes = round_id.to_le_bytes();
+    let vault_seeds = &[
+        VAULT_SEED,
+        round_id_bytes.as_ref(),
+        &[ctx.bumps.vault],
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+    
+    let mut paid_count: u32 = 0;
+    let mut total_paid: u64 = 0;
+    
+    for accounts in ctx.remaining_accounts.chunks(3) {
+        let (prediction_info, user_stats_info, user_info) = (&accounts[0], &accounts[1], &accounts[2]);
+        
+        let mut prediction = Account::<Prediction>::try_from(prediction_info)?;
+        require!(
+            prediction.round_id == round_id,
+            SocialRouletteError::InvalidRemainingAccounts
+        );
+        // Payouts only ever go to the prediction's owner
+        require_keys_eq!(
+            user_info.key(),
+            prediction.user,
+            SocialRouletteError::InvalidRemainingAccounts
+        );
+        
+        let mut user_stats = Account::<UserStats>::try_from(user_stats_info)?;
+        require_keys_eq!(
+            user_stats.user,
+            prediction.user,
+            SocialRouletteError::InvalidRemainingAccounts
+        );
+        
+        // Skip claimed positions so one stale entry doesn't sink the batch
+        if !prediction.can_claim(true) {
+            continue;
+        }
+        
+        // Losing positions are resolved too: the loss is booked. Accounts stay open
+        // so the owner can still mint the moment card; close_prediction reclaims rent
+        let winnings = calculate_prediction_winnings(round, &prediction)?;
+        if winnings == 0 {
+            user_stats.record_loss(prediction.amount)?;
+            user_stats.exit(ctx.program_id)?;
+            emit!(user_stats.updated_event());
+            
+            prediction.mark_claimed()?;
+            prediction.exit(ctx.program_id)?;
+            continue;
+        }
+        
+        require!(
+            ctx.accounts.vault.lamports() >= winnings,
+            SocialRouletteError::InsufficientVaultBalance
+        );
+        
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: user_info.clone(),
+                },
+                vault_signer,
+            ),
+            winnings,
+        )?;
+        
+        // Moment card perk: fee rebate and boost, paid by the perk vault while it lasts
+        let bonus = calculate_perk_bonus(
+            prediction.perk_winnings(winnings),
+            round.total_pool,
+            round.platform_fee_collected,
+            prediction.perk_fee_discount_bps,
+            prediction.perk_boost_bps,
+        )?;
+        let mut perk_paid = 0;
+        if bonus > 0 {
+            let perk_vault = ctx.accounts.perk_vault
+                .as_ref()
+                .ok_or(SocialRouletteError::MissingPerkVault)?;
+            perk_paid = pay_perk_bonus(
+                &perk_vault.to_account_info(),
+                ctx.bumps.perk_vault.ok_or(SocialRouletteError::MissingPerkVault)?,
+                user_info.clone(),
+                &ctx.accounts.system_program.to_account_info(),
+                bonus,
+            )?;
+            emit!(MomentPerkPaid {
+                round_id,
+                user: user_info.key(),
+                amount: perk_paid,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        let user_paid = winnings
+            .checked_add(perk_paid)
+            .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+        
+        let unlocked = user_stats.record_win(prediction.amount, user_paid)?;
+        user_stats.exit(ctx.program_id)?;
+        emit!(user_stats.updated_event());
+        user_stats.emit_unlocked(unlocked, clock.unix_timestamp);
+        
+        // Claimed, but left open (unlike claim_winnings) for the owner's moment card
+        prediction.mark_claimed()?;
+        prediction.exit(ctx.program_id)?;
+        
+        emit!(WinningsClaimed {
+            round_id,
+            user: user_info.key(),
+            amount: winnings,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        paid_count = paid_count
+            .checked_add(1)
+            .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+        total_paid = total_paid
+            .checked_add(winnings)
+            .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+    }
+    
+    emit!(WinningsDistributed {
+        round_id,
+        paid_count,
+        total_paid,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
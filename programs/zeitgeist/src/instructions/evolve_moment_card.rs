@@ -0,0 +1,89 @@
+// Evolve moment card instruction
+
+use anchor_lang::prelude::*;
+use crate::constants::MOMENT_EVOLVE_WINS;
+use crate::contexts::EvolveMomentCard;
+use crate::errors::SocialRouletteError;
+use crate::events::MomentCardEvolved;
+use crate::moment::verify_card_owner;
+use crate::state::{MomentCard, MomentRarity};
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, EvolveMomentCard<'info>>,
+    roots: Vec<[u8; 32]>,
+    proof_len: u8,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let user = ctx.accounts.user.key();
+    let proof_len = proof_len as usize;
+    let stride = 2 + proof_len;
+    
+    require!(
+        roots.len() == 1 + MOMENT_EVOLVE_WINS
+            && ctx.remaining_accounts.len() == proof_len + MOMENT_EVOLVE_WINS * stride,
+        SocialRouletteError::InvalidRemainingAccounts
+    );
+    let (proof, wins) = ctx.remaining_accounts.split_at(proof_len);
+    
+    let card = &mut ctx.accounts.moment_card;
+    require!(
+        !card.burned && card.rarity != MomentRarity::Legendary,
+        SocialRouletteError::InvalidMomentCard
+    );
+    
+    verify_card_owner(
+        &ctx.accounts.compression_program,
+        &ctx.accounts.merkle_tree,
+        user,
+        card,
+        roots[0],
+        proof,
+    )?;
+    
+    // Each later win can only ever evolve one card
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(MOMENT_EVOLVE_WINS);
+    for (accounts, root) in wins.chunks(stride).zip(roots[1..].iter().copied()) {
+        let (win_info, merkle_tree, win_proof) = (&accounts[0], &accounts[1], &accounts[2..]);
+        require!(!seen.contains(win_info.key), SocialRouletteError::InvalidWinProof);
+        
+        let mut win = Account::<MomentCard>::try_from(win_info)?;
+        require!(win.is_win_proof_for(card), SocialRouletteError::InvalidWinProof);
+        require_keys_eq!(
+            merkle_tree.key(),
+            win.merkle_tree,
+            SocialRouletteError::InvalidMomentTree
+        );
+        
+        // The signer must hold every win card, not merely have minted it
+        verify_card_owner(
+            &ctx.accounts.compression_program,
+            merkle_tree,
+            user,
+            &win,
+            root,
+            win_proof,
+        )?;
+        
+        win.win_proof_spent = true;
+        win.exit(ctx.program_id)?;
+        seen.push(win_info.key());
+    }
+    
+    // The card's URI renders this record, so the cNFT evolves in place
+    card.rarity = card.rarity.upgraded(1);
+    card.evolutions = card.evolutions.saturating_add(1);
+    
+    let moment_upgrades = &mut ctx.accounts.moment_upgrades;
+    moment_upgrades.init_if_new(user, ctx.bumps.moment_upgrades);
+    moment_upgrades.record_evolution(clock.unix_timestamp);
+    
+    emit!(MomentCardEvolved {
+        user,
+        moment_card: card.key(),
+        rarity: card.rarity,
+        evolutions: card.evolutions,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
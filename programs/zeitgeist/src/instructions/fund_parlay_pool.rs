@@ -0,0 +1,52 @@
+// Fund parlay pool instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::FundParlayPool;
+use crate::events::ParlayPoolFunded;
+use crate::errors::SocialRouletteError;
+use crate::state::ParlayPool;
+
+pub fn handler(ctx: Context<FundParlayPool>, amount: u64) -> Result<()> {
+    let parlay_pool = &mut ctx.accounts.parlay_pool;
+    let clock = Clock::get()?;
+    
+    require!(amount > 0, SocialRouletteError::InvalidPredictionAmount);
+    
+    // Initialize the pool on first funding (init_if_needed handles account creation)
+    if parlay_pool.version == 0 {
+        parlay_pool.total_liability = 0;
+        parlay_pool.total_staked = 0;
+        parlay_pool.total_paid = 0;
+        parlay_pool.bump = ctx.bumps.parlay_pool;
+        parlay_pool.version = ParlayPool::VERSION;
+        parlay_pool.reserved = [0; 32];
+    }
+    
+    // Top the vault up to rent exemption on first use
+    let vault_rent_exempt = if ctx.accounts.vault.lamports() == 0 {
+        Rent::get()?.minimum_balance(0)
+    } else {
+        0
+    };
+    
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.treasurer.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        amount
+            .checked_add(vault_rent_exempt)
+            .ok_or(SocialRouletteError::ArithmeticOverflow)?,
+    )?;
+    
+    emit!(ParlayPoolFunded {
+        treasurer: ctx.accounts.treasurer.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// Fund perk vault instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::FundPerkVault;
+use crate::events::PerkVaultFunded;
+use crate::errors::SocialRouletteError;
+
+pub fn handler(ctx: Context<FundPerkVault>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    require!(amount > 0, SocialRouletteError::InvalidPredictionAmount);
+    
+    // Top the vault up to rent exemption on first use
+    let vault_rent_exempt = if ctx.accounts.vault.lamports() == 0 {
+        Rent::get()?.minimum_balance(0)
+    } else {
+        0
+    };
+    
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.treasurer.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        amount
+            .checked_add(vault_rent_exempt)
+            .ok_or(SocialRouletteError::ArithmeticOverflow)?,
+    )?;
+    
+    emit!(PerkVaultFunded {
+        treasurer: ctx.accounts.treasurer.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,22 @@
+// Get odds instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::GetOdds;
+use crate::utils::calculate_implied_multiplier_bps;
+
+/// Per-outcome payout multipliers in basis points (0 = nothing staked yet)
+pub fn handler(ctx: Context<GetOdds>, _round_id: u64) -> Result<Vec<u64>> {
+    let global_state = &ctx.accounts.global_state;
+    let round = &ctx.accounts.round;
+
+    round.outcome_pools[..round.num_outcomes as usize]
+        .iter()
+        .map(|&outcome_pool| {
+            calculate_implied_multiplier_bps(
+                outcome_pool,
+                round.total_pool,
+                global_state.platform_fee_bps,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,22 @@
+// Grant role instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::GrantRole;
+use crate::events::RoleGranted;
+use crate::state::Role;
+
+pub fn handler(ctx: Context<GrantRole>, role: Role, member: Pubkey) -> Result<()> {
+    let roles = &mut ctx.accounts.roles;
+    let clock = Clock::get()?;
+
+    roles.grant(role, member)?;
+
+    emit!(RoleGranted {
+        role,
+        member,
+        granted_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
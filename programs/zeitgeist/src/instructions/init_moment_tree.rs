@@ -0,0 +1,59 @@
+// Init moment tree instruction
+
+use anchor_lang::prelude::*;
+use mpl_bubblegum::instructions::CreateTreeConfigCpiBuilder;
+use crate::constants::MOMENT_AUTHORITY_SEED;
+use crate::contexts::InitMomentTree;
+use crate::events::MomentTreeInitialized;
+use crate::state::MomentConfig;
+
+pub fn handler(ctx: Context<InitMomentTree>, max_depth: u32, max_buffer_size: u32) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    let authority_seeds = &[
+        MOMENT_AUTHORITY_SEED,
+        &[ctx.bumps.moment_authority],
+    ];
+    let authority_signer = &[&authority_seeds[..]];
+    
+    // A private tree: only its creator, the moment authority PDA, can mint
+    CreateTreeConfigCpiBuilder::new(&ctx.accounts.bubblegum_program.to_account_info())
+        .tree_config(&ctx.accounts.tree_authority.to_account_info())
+        .merkle_tree(&ctx.accounts.merkle_tree.to_account_info())
+        .payer(&ctx.accounts.admin.to_account_info())
+        .tree_creator(&ctx.accounts.moment_authority.to_account_info())
+        .log_wrapper(&ctx.accounts.log_wrapper.to_account_info())
+        .compression_program(&ctx.accounts.compression_program.to_account_info())
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .max_depth(max_depth)
+        .max_buffer_size(max_buffer_size)
+        .public(false)
+        .invoke_signed(authority_signer)?;
+    
+    let moment_config = &mut ctx.accounts.moment_config;
+    
+    // Initialize the config on first use (init_if_needed handles account creation)
+    if moment_config.version == 0 {
+        moment_config.authority_bump = ctx.bumps.moment_authority;
+        moment_config.rarity_tiers = MomentConfig::default_rarity_tiers();
+        moment_config.early_bettor_window = 0;
+        moment_config.comeback_loss_streak = 0;
+        moment_config.largest_stake_bonus = false;
+        moment_config.bump = ctx.bumps.moment_config;
+        moment_config.version = MomentConfig::VERSION;
+        moment_config.reserved = [0; 64];
+    }
+    
+    moment_config.merkle_tree = ctx.accounts.merkle_tree.key();
+    moment_config.collection_mint = ctx.accounts.collection_mint.key();
+    
+    emit!(MomentTreeInitialized {
+        merkle_tree: moment_config.merkle_tree,
+        collection_mint: moment_config.collection_mint,
+        max_depth,
+        max_buffer_size,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -3,7 +3,7 @@
 use anchor_lang::prelude::*;
 use crate::contexts::Initialize;
 use crate::events::GlobalStateInitialized;
-use crate::constants::PLATFORM_FEE_BPS;
+use crate::constants::{DEFAULT_EXIT_FEE_BPS, DEFAULT_MOMENT_URI_BASE, PLATFORM_FEE_BPS};
 
 pub fn handler(ctx: Context<Initialize>, platform_wallet: Pubkey) -> Result<()> {
     let global_state = &mut ctx.accounts.global_state;
@@ -17,8 +17,13 @@ pub fn handler(ctx: Context<Initialize>, platform_wallet: Pubkey) -> Result<()>
     global_state.total_rounds = 0;
     global_state.total_tournaments = 0;
     global_state.total_volume = 0;
-    global_state.paused = false;
+    global_state.pause_flags = 0;
     global_state.bump = ctx.bumps.global_state;
+    global_state.version = crate::state::GlobalState::VERSION;
+    global_state.exit_fee_bps = DEFAULT_EXIT_FEE_BPS;
+    global_state.moment_uri_base = DEFAULT_MOMENT_URI_BASE.to_string();
+    global_state.auto_rounds = 0;
+    global_state.reserved = [0; 34];
     
     emit!(GlobalStateInitialized {
         admin: global_state.admin,
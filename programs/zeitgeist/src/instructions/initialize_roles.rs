@@ -0,0 +1,31 @@
+// Initialize roles instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::InitializeRoles;
+use crate::events::RoleGranted;
+use crate::state::Role;
+
+pub fn handler(ctx: Context<InitializeRoles>) -> Result<()> {
+    let roles = &mut ctx.accounts.roles;
+    let admin = ctx.accounts.admin.key();
+    let clock = Clock::get()?;
+
+    roles.members = Vec::new();
+    roles.bump = ctx.bumps.roles;
+    roles.version = crate::state::Roles::VERSION;
+    roles.reserved = [0; 32];
+
+    // Admin starts with every role so nothing is locked out before delegation
+    for role in Role::ALL {
+        roles.grant(role, admin)?;
+
+        emit!(RoleGranted {
+            role,
+            member: admin,
+            granted_by: admin,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
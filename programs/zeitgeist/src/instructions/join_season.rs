@@ -0,0 +1,52 @@
+// Join season instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::JoinSeason;
+use crate::events::SeasonJoined;
+use crate::errors::SocialRouletteError;
+use crate::state::SeasonEntry;
+
+pub fn handler(ctx: Context<JoinSeason>, season_id: u64) -> Result<()> {
+    let season = &ctx.accounts.season;
+    let user_stats = &ctx.accounts.user_stats;
+    let season_entry = &mut ctx.accounts.season_entry;
+    let clock = Clock::get()?;
+    
+    // Joining opens with the season, so every baseline is taken inside it
+    require!(
+        season.is_active(clock.unix_timestamp),
+        SocialRouletteError::SeasonNotActive
+    );
+    
+    season_entry.season_id = season_id;
+    season_entry.user = ctx.accounts.user.key();
+    
+    // Only activity after joining counts, except on the all-time board
+    if season.global {
+        season_entry.baseline_net_profit = 0;
+        season_entry.baseline_wins = 0;
+        season_entry.baseline_losses = 0;
+        season_entry.baseline_wagered = 0;
+        season_entry.baseline_pending_stake = 0;
+        season_entry.baseline_predictions = 0;
+    } else {
+        season_entry.baseline_net_profit = user_stats.net_profit;
+        season_entry.baseline_wins = user_stats.total_wins;
+        season_entry.baseline_losses = user_stats.total_losses;
+        season_entry.baseline_wagered = user_stats.total_wagered;
+        season_entry.baseline_pending_stake = user_stats.pending_stake;
+        season_entry.baseline_predictions = user_stats.total_predictions;
+    }
+    
+    season_entry.bump = ctx.bumps.season_entry;
+    season_entry.version = SeasonEntry::VERSION;
+    season_entry.reserved = [0; 16];
+    
+    emit!(SeasonJoined {
+        season_id,
+        user: season_entry.user,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,33 @@
+// Migrate global state instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::MigrateGlobalState;
+use crate::errors::SocialRouletteError;
+use crate::events::AccountMigrated;
+use crate::state::{migrate_account, GlobalState};
+
+pub fn handler(ctx: Context<MigrateGlobalState>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let (from_version, global_state) = migrate_account::<GlobalState>(
+        &ctx.accounts.global_state.to_account_info(),
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    // Any failure here reverts the realloc above
+    require_keys_eq!(
+        global_state.admin,
+        ctx.accounts.admin.key(),
+        SocialRouletteError::Unauthorized
+    );
+
+    emit!(AccountMigrated {
+        account: ctx.accounts.global_state.key(),
+        from_version,
+        to_version: GlobalState::VERSION,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
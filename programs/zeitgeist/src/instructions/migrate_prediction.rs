@@ -0,0 +1,25 @@
+// Migrate prediction instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::MigratePrediction;
+use crate::events::AccountMigrated;
+use crate::state::{migrate_account, Prediction};
+
+pub fn handler(ctx: Context<MigratePrediction>, _round_id: u64, _user: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let (from_version, _) = migrate_account::<Prediction>(
+        &ctx.accounts.prediction.to_account_info(),
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(AccountMigrated {
+        account: ctx.accounts.prediction.key(),
+        from_version,
+        to_version: Prediction::VERSION,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
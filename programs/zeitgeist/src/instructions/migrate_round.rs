@@ -0,0 +1,25 @@
+// Migrate round instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::MigrateRound;
+use crate::events::AccountMigrated;
+use crate::state::{migrate_account, Round};
+
+pub fn handler(ctx: Context<MigrateRound>, _round_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let (from_version, _) = migrate_account::<Round>(
+        &ctx.accounts.round.to_account_info(),
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(AccountMigrated {
+        account: ctx.accounts.round.key(),
+        from_version,
+        to_version: Round::VERSION,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
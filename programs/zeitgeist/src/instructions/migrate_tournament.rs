@@ -0,0 +1,25 @@
+// Migrate tournament instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::MigrateTournament;
+use crate::events::AccountMigrated;
+use crate::state::{migrate_account, Tournament};
+
+pub fn handler(ctx: Context<MigrateTournament>, _tournament_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let (from_version, _) = migrate_account::<Tournament>(
+        &ctx.accounts.tournament.to_account_info(),
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(AccountMigrated {
+        account: ctx.accounts.tournament.key(),
+        from_version,
+        to_version: Tournament::VERSION,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
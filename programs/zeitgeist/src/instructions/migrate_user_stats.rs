@@ -0,0 +1,25 @@
+// Migrate user stats instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::MigrateUserStats;
+use crate::events::AccountMigrated;
+use crate::state::{migrate_account, UserStats};
+
+pub fn handler(ctx: Context<MigrateUserStats>, _user: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let (from_version, _) = migrate_account::<UserStats>(
+        &ctx.accounts.user_stats.to_account_info(),
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(AccountMigrated {
+        account: ctx.accounts.user_stats.key(),
+        from_version,
+        to_version: UserStats::VERSION,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
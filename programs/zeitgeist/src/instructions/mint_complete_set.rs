@@ -0,0 +1,85 @@
+// Mint complete set instruction
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, MintTo};
+use crate::contexts::MintCompleteSet;
+use crate::events::CompleteSetMinted;
+use crate::errors::SocialRouletteError;
+use crate::constants::*;
+use crate::utils::validate_outcome_token_accounts;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MintCompleteSet<'info>>,
+    round_id: u64,
+    amount: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let round_info = ctx.accounts.round.to_account_info();
+    let round = &mut ctx.accounts.round;
+    
+    require!(
+        round.is_betting_active(clock.unix_timestamp),
+        SocialRouletteError::BettingEnded
+    );
+    
+    require!(
+        round.outcome_tokens_ready(),
+        SocialRouletteError::OutcomeTokensNotReady
+    );
+    
+    require!(
+        (MIN_PREDICTION_AMOUNT..=MAX_PREDICTION_AMOUNT).contains(&amount),
+        SocialRouletteError::InvalidPredictionAmount
+    );
+    
+    validate_outcome_token_accounts(ctx.program_id, round_id, round.num_outcomes, ctx.remaining_accounts)?;
+    
+    // Lock the stake in the vault as token collateral
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    // Create PDA signer seeds for the round (mint authority)
+    let round_id_bytes = round_id.to_le_bytes();
+    let round_seeds = &[
+        ROUND_SEED,
+        round_id_bytes.as_ref(),
+        &[round.bump],
+    ];
+    let round_signer = &[&round_seeds[..]];
+    
+    // One token of every outcome per lamport staked
+    for pair in ctx.remaining_accounts.chunks(2) {
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: pair[0].clone(),
+                    to: pair[1].clone(),
+                    authority: round_info.clone(),
+                },
+                round_signer,
+            ),
+            amount,
+        )?;
+    }
+    
+    round.add_token_collateral(amount)?;
+    
+    emit!(CompleteSetMinted {
+        round_id,
+        user: ctx.accounts.user.key(),
+        amount,
+        token_collateral: round.token_collateral,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
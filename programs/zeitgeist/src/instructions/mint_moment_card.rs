@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
-use mpl_bubblegum::{
-    instructions::MintV1CpiBuilder,
-    types::{MetadataArgs, Collection, TokenProgramVersion, TokenStandard},
-};
 use crate::contexts::MintMomentCard;
 use crate::errors::SocialRouletteError;
+use crate::constants::ACHIEVEMENT_LEGENDARY_MOMENT;
+use crate::moment::{mint_card, MomentCollection, MomentTree};
+use crate::events::MomentCardMinted;
+use crate::state::{MomentCard, MomentRarity};
+use crate::utils::{calculate_implied_odds_bps, calculate_prediction_winnings};
 
 pub fn handler(ctx: Context<MintMomentCard>, round_id: u64) -> Result<()> {
     let round = &ctx.accounts.round;
@@ -12,53 +13,94 @@ pub fn handler(ctx: Context<MintMomentCard>, round_id: u64) -> Result<()> {
     
     require!(round.is_settled(), SocialRouletteError::RoundNotSettled);
 
-     let tree_authority_info = &ctx.accounts.tree_authority;
-    if tree_authority_info.data_is_empty() {
-        // Tree authority not initialized - this is expected on first use
-        // Bubblegum's mint_v1 will handle this automatically
-        msg!("Tree authority will be initialized during mint");
-    }
-    
-    let rarity = if prediction.is_winner(round.winning_outcome) {
-        let win_percentage = (round.winning_pool * 100) / round.total_pool;
-        if win_percentage < 20 { "Legendary" }
-        else if win_percentage < 40 { "Epic" }
-        else { "Rare" }
-    } else { "Common" };
+    // Rarer the less the crowd backed the winning outcome, bumped by modifiers for wins
+    let moment_config = &ctx.accounts.moment_config;
+    let won = prediction.is_winner(round.winning_outcome);
+    let odds_bps = calculate_implied_odds_bps(round.winning_pool, round.total_pool);
+    let rarity = if won {
+        let modifiers = moment_config.rarity_modifiers(round, prediction);
+        moment_config.base_rarity(won, odds_bps).upgraded(modifiers)
+    } else {
+        MomentRarity::Common
+    };
 
-    let metadata_uri = format!("https://api.zeitgeist.game/moments/{}/{}", round_id, ctx.accounts.user.key());
+    let clock = Clock::get()?;
+    let payout = calculate_prediction_winnings(round, prediction)?;
 
-    let metadata = MetadataArgs {
-        name: format!("Zeitgeist Round #{}", round_id),
-        symbol: "ZGST".to_string(),
-        uri: metadata_uri,
-        seller_fee_basis_points: 0,
-        primary_sale_happened: true,
-        is_mutable: false,
-        edition_nonce: None,
-        token_standard: Some(TokenStandard::NonFungible),
-        collection: Some(Collection { 
-            verified: false, 
-            key: ctx.accounts.collection_mint.key()  // Works with 1.4.0
-        }),
-        uses: None,
-        token_program_version: TokenProgramVersion::Original,
-        creators: vec![],
-    };
+    // Record the card's attributes on-chain; the metadata URI points here
+    let moment_card = &mut ctx.accounts.moment_card;
+    moment_card.round_id = round_id;
+    moment_card.round_end_time = round.end_time;
+    moment_card.prediction = prediction.key();
+    moment_card.user = ctx.accounts.user.key();
+    moment_card.question = round.question.clone();
+    moment_card.picked_outcome = prediction.outcome;
+    moment_card.winning_outcome = round.winning_outcome;
+    moment_card.stake = prediction.amount;
+    moment_card.payout_multiplier_bps = MomentCard::payout_multiplier_bps(prediction.amount, payout)?;
+    moment_card.rarity = rarity;
+    moment_card.minted_at = clock.unix_timestamp;
+    moment_card.evolutions = 0;
+    moment_card.win_proof_spent = false;
+    moment_card.last_perk_day = 0;
+    moment_card.bump = ctx.bumps.moment_card;
+    moment_card.version = MomentCard::VERSION;
+    moment_card.reserved = [0; 32];
 
-    MintV1CpiBuilder::new(&ctx.accounts.bubblegum_program.to_account_info())
-        .tree_config(&ctx.accounts.tree_authority.to_account_info())
-        .leaf_owner(&ctx.accounts.user.to_account_info())
-        .leaf_delegate(&ctx.accounts.user.to_account_info())
-        .merkle_tree(&ctx.accounts.merkle_tree.to_account_info())
-        .payer(&ctx.accounts.user.to_account_info())
-        .tree_creator_or_delegate(&ctx.accounts.user.to_account_info())
-        .log_wrapper(&ctx.accounts.log_wrapper.to_account_info())
-        .compression_program(&ctx.accounts.compression_program.to_account_info())
-        .system_program(&ctx.accounts.system_program.to_account_info())
-        .metadata(metadata)
-        .invoke()?;
+    let minted = mint_card(
+        &MomentTree {
+            bubblegum_program: &ctx.accounts.bubblegum_program,
+            tree_authority: &ctx.accounts.tree_authority,
+            merkle_tree: &ctx.accounts.merkle_tree,
+            log_wrapper: &ctx.accounts.log_wrapper,
+            compression_program: &ctx.accounts.compression_program,
+            system_program: &ctx.accounts.system_program,
+        },
+        &MomentCollection {
+            moment_authority: &ctx.accounts.moment_authority,
+            authority_bump: ctx.accounts.moment_config.authority_bump,
+            collection_mint: &ctx.accounts.collection_mint,
+            collection_metadata: &ctx.accounts.collection_metadata,
+            collection_edition: &ctx.accounts.collection_edition,
+            bubblegum_signer: &ctx.accounts.bubblegum_signer,
+            token_metadata_program: &ctx.accounts.token_metadata_program,
+        },
+        &ctx.accounts.user,
+        moment_card.key(),
+        round_id,
+        &ctx.accounts.global_state.moment_uri_base,
+    )?;
+    moment_card.set_leaf(ctx.accounts.merkle_tree.key(), &minted);
+    
+    msg!(
+        "Minted cNFT for round {} with rarity: {}",
+        round_id,
+        ctx.accounts.moment_config.rarity_tiers[rarity as usize].name
+    );
+    
+    emit!(MomentCardMinted {
+        round_id,
+        user: moment_card.user,
+        moment_card: moment_card.key(),
+        asset_id: moment_card.asset_id,
+        rarity,
+        payout_multiplier_bps: moment_card.payout_multiplier_bps,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    if rarity == MomentRarity::Legendary {
+        let user_stats = &mut ctx.accounts.user_stats;
+        let unlocked = user_stats.unlock(ACHIEVEMENT_LEGENDARY_MOMENT);
+        if unlocked != 0 {
+            emit!(user_stats.updated_event());
+            user_stats.emit_unlocked(unlocked, clock.unix_timestamp);
+        }
+    }
+    
+    // A position already paid out has nothing left to claim
+    if ctx.accounts.prediction.claimed {
+        ctx.accounts.prediction.close(ctx.accounts.user.to_account_info())?;
+    }
     
-    msg!("Minted cNFT for round {} with rarity: {}", round_id, rarity);
     Ok(())
 }
\ No newline at end of file
@@ -12,4 +12,58 @@ pub mod refund_prediction;  // ← ADD THIS
 pub mod unpause_program;  // ← ADD THIS
 pub mod pause_program;
 pub mod mint_moment_card;
+pub mod initialize_roles;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod update_platform_wallet;
+pub mod set_round_frozen;
+pub mod migrate_global_state;
+pub mod migrate_round;
+pub mod migrate_prediction;
+pub mod migrate_user_stats;
+pub mod migrate_tournament;
+pub mod create_round_series;
+pub mod spawn_next_round;
+pub mod set_round_series_active;
+pub mod create_round_auto;
+pub mod withdraw_prediction;
+pub mod set_exit_fee;
+pub mod get_odds;
+pub mod create_amm_market;
+pub mod buy_amm_shares;
+pub mod sell_amm_shares;
+pub mod settle_amm_market;
+pub mod redeem_amm_shares;
+pub mod withdraw_amm_liquidity;
+pub mod create_outcome_mint;
+pub mod mint_complete_set;
+pub mod burn_complete_set;
+pub mod redeem_outcome_tokens;
+pub mod create_scalar_round;
+pub mod fund_parlay_pool;
+pub mod withdraw_parlay_pool;
+pub mod place_parlay;
+pub mod settle_parlay;
+pub mod create_session;
+pub mod revoke_session;
+pub mod deposit_balance;
+pub mod withdraw_balance;
+pub mod distribute_winnings;
+pub mod refund_predictions;
+pub mod record_loss;
+pub mod complete_tournament;
+pub mod create_season;
+pub mod join_season;
+pub mod submit_season_score;
+pub mod close_season;
+pub mod set_moment_uri_base;
+pub mod init_moment_tree;
+pub mod set_moment_rarity_config;
+pub mod combine_moment_cards;
+pub mod evolve_moment_card;
+pub mod fund_perk_vault;
+pub mod cancel_amm_market;
+pub mod refund_outcome_tokens;
+pub mod close_prediction;
+pub mod claim_season_reward;
 
@@ -1,8 +1,28 @@
 use anchor_lang::prelude::*;
+use crate::constants::PAUSE_ALL;
 use crate::contexts::PauseProgram;
+use crate::errors::SocialRouletteError;
+use crate::events::ProgramPaused;
 
-pub fn handler(ctx: Context<PauseProgram>) -> Result<()> {
+pub fn handler(ctx: Context<PauseProgram>, flags: u8) -> Result<()> {
     let global_state = &mut ctx.accounts.global_state;
-    global_state.paused = true;
+    let clock = Clock::get()?;
+
+    require!(
+        flags != 0 && flags & !PAUSE_ALL == 0,
+        SocialRouletteError::InvalidPauseFlags
+    );
+
+    // Only report families that were not already paused
+    let changed_flags = flags & !global_state.pause_flags;
+    global_state.pause_flags |= flags;
+
+    emit!(ProgramPaused {
+        changed_flags,
+        pause_flags: global_state.pause_flags,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,123 @@
+// Place parlay instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::PlaceParlay;
+use crate::events::ParlayPlaced;
+use crate::errors::SocialRouletteError;
+use crate::constants::*;
+use crate::state::{Parlay, ParlayLeg, ParlayPick, Round, RoundKind};
+use crate::utils::calculate_implied_multiplier_bps;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PlaceParlay<'info>>,
+    parlay_id: u64,
+    picks: Vec<ParlayPick>,
+    amount: u64,
+    min_payout: u64,
+) -> Result<()> {
+    let global_state = &ctx.accounts.global_state;
+    let clock = Clock::get()?;
+    
+    require!(
+        (MIN_PARLAY_LEGS..=MAX_PARLAY_LEGS).contains(&picks.len()),
+        SocialRouletteError::InvalidParlay
+    );
+    require!(
+        ctx.remaining_accounts.len() == picks.len(),
+        SocialRouletteError::InvalidParlay
+    );
+    
+    require!(
+        (MIN_PREDICTION_AMOUNT..=MAX_PREDICTION_AMOUNT).contains(&amount),
+        SocialRouletteError::InvalidPredictionAmount
+    );
+    
+    // Quote each leg from its round's current parimutuel odds, capped per leg;
+    // settlement pays the lower of this and the closing odds
+    let mut legs = Vec::with_capacity(picks.len());
+    for (i, (pick, round_info)) in picks.iter().zip(ctx.remaining_accounts).enumerate() {
+        require!(
+            picks[..i].iter().all(|other| other.round_id != pick.round_id),
+            SocialRouletteError::InvalidParlay
+        );
+        
+        let round = Account::<Round>::try_from(round_info)?;
+        require!(round.round_id == pick.round_id, SocialRouletteError::InvalidParlay);
+        require!(!round.frozen, SocialRouletteError::RoundFrozen);
+        require!(
+            round.kind == RoundKind::Categorical,
+            SocialRouletteError::InvalidRoundKind
+        );
+        require!(
+            round.is_betting_active(clock.unix_timestamp),
+            SocialRouletteError::BettingEnded
+        );
+        require!(
+            pick.outcome < round.num_outcomes,
+            SocialRouletteError::InvalidOutcome
+        );
+        
+        let multiplier_bps = calculate_implied_multiplier_bps(
+            round.outcome_pools[pick.outcome as usize],
+            round.total_pool,
+            global_state.platform_fee_bps,
+        )?
+        .min(MAX_PARLAY_LEG_MULTIPLIER_BPS);
+        // A leg paying under 1x (or with nothing staked) can only shrink the parlay
+        require!(multiplier_bps >= 10000, SocialRouletteError::InvalidParlay);
+        
+        legs.push(ParlayLeg {
+            round_id: pick.round_id,
+            outcome: pick.outcome,
+            multiplier_bps,
+        });
+    }
+    
+    let multiplier_bps = Parlay::combined_multiplier_bps(legs.iter().map(|leg| &leg.multiplier_bps))?;
+    let potential_payout = Parlay::payout_for(amount, multiplier_bps)?;
+    require!(
+        potential_payout >= min_payout,
+        SocialRouletteError::SlippageExceeded
+    );
+    
+    // Escrow the stake in the parlay vault
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    // The bankroll plus this stake must cover every open parlay winning
+    let available = ctx.accounts.vault.lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    ctx.accounts.parlay_pool.reserve(amount, potential_payout, available)?;
+    
+    let parlay = &mut ctx.accounts.parlay;
+    parlay.parlay_id = parlay_id;
+    parlay.user = ctx.accounts.user.key();
+    parlay.legs = legs;
+    parlay.amount = amount;
+    parlay.potential_payout = potential_payout;
+    parlay.timestamp = clock.unix_timestamp;
+    parlay.bump = ctx.bumps.parlay;
+    parlay.version = Parlay::VERSION;
+    parlay.reserved = [0; 32];
+    
+    emit!(ParlayPlaced {
+        parlay_id,
+        user: parlay.user,
+        round_ids: parlay.legs.iter().map(|leg| leg.round_id).collect(),
+        outcomes: parlay.legs.iter().map(|leg| leg.outcome).collect(),
+        amount,
+        multiplier_bps,
+        potential_payout,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
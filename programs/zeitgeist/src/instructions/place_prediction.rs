@@ -4,15 +4,19 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use crate::contexts::PlacePrediction;
 //use crate::state::UserStats;
-use crate::events::PredictionPlaced;
+use crate::events::{MomentPerkApplied, PredictionPlaced};
 use crate::errors::SocialRouletteError;
 use crate::constants::*;
+use crate::moment::verify_card_owner;
+use crate::utils::{calculate_implied_multiplier_bps, transfer_from_program_account};
 
-pub fn handler(
-    ctx: Context<PlacePrediction>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PlacePrediction<'info>>,
     round_id: u64,
     outcome: u8,
     amount: u64,
+    min_implied_multiplier_bps: Option<u64>,
+    card_root: Option<[u8; 32]>,
 ) -> Result<()> {
     let global_state = &mut ctx.accounts.global_state;
     let round = &mut ctx.accounts.round;
@@ -28,7 +32,7 @@ pub fn handler(
     
     // Validate amount
     require!(
-        amount >= MIN_PREDICTION_AMOUNT && amount <= MAX_PREDICTION_AMOUNT,
+        (MIN_PREDICTION_AMOUNT..=MAX_PREDICTION_AMOUNT).contains(&amount),
         SocialRouletteError::InvalidPredictionAmount
     );
     
@@ -38,48 +42,150 @@ pub fn handler(
         SocialRouletteError::InvalidOutcome
     );
     
-    // Validate max predictions
+    // Only the user, or a live session key within its cap, may bet for the user
+    if ctx.accounts.payer.key() != ctx.accounts.user.key() {
+        let session_token = ctx.accounts.session_token
+            .as_mut()
+            .ok_or(SocialRouletteError::SessionUnauthorized)?;
+        session_token.record_spend(amount, clock.unix_timestamp)?;
+    }
+    
+    // A fresh account is all zeroes; an existing position already records its user
+    let new_position = prediction.user == Pubkey::default();
+    
+    // Validate max predictions (top-ups don't open a new position)
     require!(
-        round.total_predictions < MAX_PREDICTIONS_PER_ROUND,
+        !new_position || round.total_predictions < MAX_PREDICTIONS_PER_ROUND,
         SocialRouletteError::MaxPredictionsReached
     );
     
-    // Transfer funds from payer to vault
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.payer.to_account_info(),
-                to: ctx.accounts.vault.to_account_info(),
-            },
-        ),
-        amount,
-    )?;
+    // Fund the bet from the user's custodial balance if given, else from the payer
+    match ctx.accounts.balance.as_mut() {
+        Some(balance) => {
+            balance.debit(amount)?;
+            transfer_from_program_account(
+                &balance.to_account_info(),
+                &ctx.accounts.vault.to_account_info(),
+                amount,
+            )?;
+        }
+        None => {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: ctx.accounts.vault.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+    }
+    
+    // Initialize prediction fields on the first bet
+    if new_position {
+        prediction.round_id = round_id;
+        prediction.user = ctx.accounts.user.key();
+        prediction.amount = 0;
+        prediction.outcome = outcome;
+        prediction.timestamp = clock.unix_timestamp;
+        prediction.claimed = false;
+        prediction.bump = ctx.bumps.prediction;
+        prediction.version = crate::state::Prediction::VERSION;
+        prediction.outcome_amounts = [0; MAX_OUTCOMES as usize];
+        prediction.perk_fee_discount_bps = 0;
+        prediction.perk_boost_bps = 0;
+        prediction.loss_streak_at_entry = user_stats.loss_streak;
+        prediction.perk_amount = 0;
+        prediction.reserved = [0; 16];
+    }
     
-    // Initialize prediction fields
-    prediction.round_id = round_id;
-    prediction.user = ctx.accounts.user.key();
-    prediction.amount = amount;
-    prediction.outcome = outcome;
-    prediction.timestamp = clock.unix_timestamp;
-    prediction.claimed = false;
-    prediction.bump = ctx.bumps.prediction;
+    // Add to the position on this outcome
+    let position_amount = prediction.add_stake(outcome, amount)?;
     
     // Update round
-    round.add_prediction(amount, outcome)?;
+    round.add_prediction(amount, outcome, new_position)?;
+    round.record_stake(prediction.amount);
+    
+    // Reject the bet if the pool moved against the user before it landed
+    if let Some(min_multiplier_bps) = min_implied_multiplier_bps {
+        let multiplier_bps = calculate_implied_multiplier_bps(
+            round.outcome_pools[outcome as usize],
+            round.total_pool,
+            global_state.platform_fee_bps,
+        )?;
+        
+        require!(
+            multiplier_bps >= min_multiplier_bps,
+            SocialRouletteError::SlippageExceeded
+        );
+    }
     
-    // ✅ Initialize user stats if first time (init_if_needed handles account creation)
-    if user_stats.total_predictions == 0 {
+    // A held moment card grants its rarity's perk on this bet, once per card per day
+    if let Some(moment_card) = ctx.accounts.moment_card.as_mut() {
+        let root = card_root.ok_or(SocialRouletteError::InvalidMomentCard)?;
+        let merkle_tree = ctx.accounts.card_merkle_tree
+            .as_ref()
+            .ok_or(SocialRouletteError::InvalidMomentTree)?;
+        let compression_program = ctx.accounts.compression_program
+            .as_ref()
+            .ok_or(SocialRouletteError::InvalidMomentTree)?;
+        
+        verify_card_owner(
+            compression_program,
+            merkle_tree,
+            ctx.accounts.user.key(),
+            moment_card,
+            root,
+            ctx.remaining_accounts,
+        )?;
+        moment_card.use_perk(clock.unix_timestamp)?;
+        
+        let tier = moment_card.rarity as usize;
+        prediction.apply_perk(MOMENT_PERK_FEE_DISCOUNT_BPS[tier], MOMENT_PERK_BOOST_BPS[tier], amount)?;
+        
+        emit!(MomentPerkApplied {
+            round_id,
+            user: ctx.accounts.user.key(),
+            moment_card: moment_card.key(),
+            rarity: moment_card.rarity,
+            fee_discount_bps: prediction.perk_fee_discount_bps,
+            boost_bps: prediction.perk_boost_bps,
+            amount,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    
+    // ✅ Initialize user stats if first time (init_if_needed handles account creation).
+    // A fresh account is all zeroes; refunds can bring total_predictions back to 0
+    if user_stats.user == Pubkey::default() {
         user_stats.user = ctx.accounts.user.key();
         user_stats.total_wins = 0;
         user_stats.total_wagered = 0;
         user_stats.total_won = 0;
         user_stats.net_profit = 0;
         user_stats.bump = ctx.bumps.user_stats;
+        user_stats.version = crate::state::UserStats::VERSION;
+        user_stats.pending_stake = 0;
+        user_stats.total_losses = 0;
+        user_stats.total_refunded = 0;
+        user_stats.current_streak = 0;
+        user_stats.longest_streak = 0;
+        user_stats.xp = 0;
+        user_stats.achievements = 0;
+        user_stats.last_active_day = 0;
+        user_stats.daily_rounds = [0; HEATMAP_DAYS];
+        user_stats.loss_streak = 0;
+        user_stats.reserved = [0; 5];
     }
     
     // Update user stats
-    user_stats.record_prediction(amount)?;
+    user_stats.record_prediction(amount, new_position)?;
+    if new_position {
+        user_stats.record_activity(clock.unix_timestamp);
+    }
+    emit!(user_stats.updated_event());
     
     // Update global volume
     global_state.add_volume(amount)?;
@@ -89,6 +195,7 @@ pub fn handler(
         user: ctx.accounts.user.key(),
         outcome,
         amount,
+        position_amount,
         timestamp: clock.unix_timestamp,
     });
     
@@ -0,0 +1,34 @@
+// Record loss instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::RecordLoss;
+use crate::errors::SocialRouletteError;
+use crate::utils::calculate_prediction_winnings;
+
+pub fn handler(ctx: Context<RecordLoss>, _round_id: u64) -> Result<()> {
+    let round = &ctx.accounts.round;
+    let prediction = &mut ctx.accounts.prediction;
+    let user_stats = &mut ctx.accounts.user_stats;
+    
+    require!(round.is_settled(), SocialRouletteError::RoundNotSettled);
+    
+    require!(
+        prediction.can_claim(true),
+        SocialRouletteError::AlreadyClaimed
+    );
+    
+    // Winners resolve through claim_winnings so they can't skip their payout here
+    require!(
+        calculate_prediction_winnings(round, prediction)? == 0,
+        SocialRouletteError::PredictionWon
+    );
+    
+    user_stats.record_loss(prediction.amount)?;
+    prediction.mark_claimed()?;
+    
+    emit!(user_stats.updated_event());
+    
+    // Note: Account closure handled automatically by `close = user` in context
+    
+    Ok(())
+}
@@ -0,0 +1,60 @@
+// Redeem AMM shares instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::RedeemAmmShares;
+use crate::events::AmmSharesRedeemed;
+use crate::errors::SocialRouletteError;
+use crate::constants::AMM_VAULT_SEED;
+
+pub fn handler(ctx: Context<RedeemAmmShares>, market_id: u64) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let position = &ctx.accounts.position;
+    let clock = Clock::get()?;
+
+    let payout = if market.is_cancelled() {
+        // Traders get back the collateral they paid in and haven't sold back
+        market.refund(position)?
+    } else {
+        require!(market.is_settled(), SocialRouletteError::RoundNotSettled);
+
+        // Each winning share pays one lamport; losing shares are worthless
+        let payout = position.shares[market.winning_outcome as usize];
+        require!(payout > 0, SocialRouletteError::NoWinnings);
+        market.redeem(payout)?;
+        payout
+    };
+
+    // Create PDA signer seeds for vault
+    let market_id_bytes = market_id.to_le_bytes();
+    let vault_seeds = &[
+        AMM_VAULT_SEED,
+        market_id_bytes.as_ref(),
+        &[ctx.bumps.vault],
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+
+    if payout > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.user.to_account_info(),
+                },
+                vault_signer,
+            ),
+            payout,
+        )?;
+    }
+
+    emit!(AmmSharesRedeemed {
+        market_id,
+        user: ctx.accounts.user.key(),
+        amount: payout,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // Note: Account closure handled automatically by `close = user` in context
+
+    Ok(())
+}
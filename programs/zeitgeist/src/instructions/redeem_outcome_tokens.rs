@@ -0,0 +1,68 @@
+// Redeem outcome tokens instruction
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn};
+use crate::contexts::RedeemOutcomeTokens;
+use crate::events::OutcomeTokensRedeemed;
+use crate::errors::SocialRouletteError;
+use crate::constants::VAULT_SEED;
+
+pub fn handler(ctx: Context<RedeemOutcomeTokens>, round_id: u64, amount: u64) -> Result<()> {
+    let round = &mut ctx.accounts.round;
+    let clock = Clock::get()?;
+    
+    require!(round.is_settled(), SocialRouletteError::RoundNotSettled);
+    
+    require!(amount > 0, SocialRouletteError::InvalidPredictionAmount);
+    
+    // Winning tokens share the remaining token collateral pro rata;
+    // losing tokens have no mint to redeem against
+    let payout = round.calc_token_redemption(amount, ctx.accounts.winning_mint.supply)?;
+    require!(payout > 0, SocialRouletteError::NoWinnings);
+    
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.winning_mint.to_account_info(),
+                from: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    round.remove_token_collateral(payout)?;
+    
+    // Create PDA signer seeds for vault
+    let round_id_bytes = round_id.to_le_bytes();
+    let vault_seeds = &[
+        VAULT_SEED,
+        round_id_bytes.as_ref(),
+        &[ctx.bumps.vault],
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+    
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.user.to_account_info(),
+            },
+            vault_signer,
+        ),
+        payout,
+    )?;
+    
+    emit!(OutcomeTokensRedeemed {
+        round_id,
+        user: ctx.accounts.user.key(),
+        outcome: round.winning_outcome,
+        tokens: amount,
+        amount: payout,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,73 @@
+// Refund outcome tokens instruction
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn};
+use crate::contexts::RefundOutcomeTokens;
+use crate::events::OutcomeTokensRedeemed;
+use crate::errors::SocialRouletteError;
+use crate::constants::VAULT_SEED;
+
+pub fn handler(
+    ctx: Context<RefundOutcomeTokens>,
+    round_id: u64,
+    outcome: u8,
+    amount: u64,
+) -> Result<()> {
+    let round = &mut ctx.accounts.round;
+    let clock = Clock::get()?;
+    
+    require!(round.is_cancelled(), SocialRouletteError::RoundNotCancelled);
+    
+    require!(amount > 0, SocialRouletteError::InvalidPredictionAmount);
+    
+    // With no winner, holders of any single outcome get back their cut of the
+    // complete sets; burning a full set still returns one lamport per set
+    let refund = round.calc_cancelled_token_refund(amount)?;
+    require!(refund > 0, SocialRouletteError::NoRefund);
+    
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.outcome_mint.to_account_info(),
+                from: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    round.remove_token_collateral(refund)?;
+    
+    // Create PDA signer seeds for vault
+    let round_id_bytes = round_id.to_le_bytes();
+    let vault_seeds = &[
+        VAULT_SEED,
+        round_id_bytes.as_ref(),
+        &[ctx.bumps.vault],
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+    
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.user.to_account_info(),
+            },
+            vault_signer,
+        ),
+        refund,
+    )?;
+    
+    emit!(OutcomeTokensRedeemed {
+        round_id,
+        user: ctx.accounts.user.key(),
+        outcome,
+        tokens: amount,
+        amount: refund,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -44,13 +44,22 @@ pub fn handler(ctx: Context<RefundPrediction>, round_id: u64) -> Result<()> {
     ];
     let vault_signer = &[&vault_seeds[..]];
 
+    // Pay into the custodial balance if given, else the wallet
+    let recipient = match ctx.accounts.balance.as_mut() {
+        Some(balance) => {
+            balance.credit(refund_amount)?;
+            balance.to_account_info()
+        }
+        None => ctx.accounts.user.to_account_info(),
+    };
+
     // CPI to System Program
     anchor_lang::system_program::transfer(
         CpiContext::new_with_signer(
             ctx.accounts.system_program.to_account_info(),
             anchor_lang::system_program::Transfer {
                 from: ctx.accounts.vault.to_account_info(),
-                to: ctx.accounts.user.to_account_info(),
+                to: recipient,
             },
             vault_signer,
         ),
@@ -59,6 +68,10 @@ pub fn handler(ctx: Context<RefundPrediction>, round_id: u64) -> Result<()> {
     // 5. Mark prediction as refunded (using claimed flag)
     prediction.mark_claimed()?;
     
+    let user_stats = &mut ctx.accounts.user_stats;
+    user_stats.record_refund(refund_amount)?;
+    emit!(user_stats.updated_event());
+    
     // 6. Emit refund event
     emit!(PredictionRefunded {
         round_id,
@@ -0,0 +1,110 @@
+// Refund predictions instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::RefundPredictions;
+use crate::events::{PredictionRefunded, PredictionsRefunded};
+use crate::errors::SocialRouletteError;
+use crate::constants::VAULT_SEED;
+use crate::state::{Prediction, UserStats};
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RefundPredictions<'info>>,
+    round_id: u64,
+) -> Result<()> {
+    let round = &ctx.accounts.round;
+    let clock = Clock::get()?;
+    
+    require!(round.is_cancelled(), SocialRouletteError::RoundNotCancelled);
+    
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len().is_multiple_of(3),
+        SocialRouletteError::InvalidRemainingAccounts
+    );
+    
+    // Create PDA signer seeds for vault
+    let round_id_bytes = round_id.to_le_bytes();
+    let vault_seeds = &[
+        VAULT_SEED,
+        round_id_bytes.as_ref(),
+        &[ctx.bumps.vault],
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+    
+    let mut refunded_count: u32 = 0;
+    let mut total_refunded: u64 = 0;
+    
+    for accounts in ctx.remaining_accounts.chunks(3) {
+        let (prediction_info, user_stats_info, user_info) = (&accounts[0], &accounts[1], &accounts[2]);
+        
+        let prediction = Account::<Prediction>::try_from(prediction_info)?;
+        require!(
+            prediction.round_id == round_id,
+            SocialRouletteError::InvalidRemainingAccounts
+        );
+        // Stakes and rent only ever go back to the prediction's owner
+        require_keys_eq!(
+            user_info.key(),
+            prediction.user,
+            SocialRouletteError::InvalidRemainingAccounts
+        );
+        
+        let mut user_stats = Account::<UserStats>::try_from(user_stats_info)?;
+        require_keys_eq!(
+            user_stats.user,
+            prediction.user,
+            SocialRouletteError::InvalidRemainingAccounts
+        );
+        
+        // Positions already refunded individually are skipped
+        let refund_amount = if prediction.claimed { 0 } else { prediction.amount };
+        
+        if refund_amount > 0 {
+            require!(
+                ctx.accounts.vault.lamports() >= refund_amount,
+                SocialRouletteError::InsufficientVaultBalance
+            );
+            
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.vault.to_account_info(),
+                        to: user_info.clone(),
+                    },
+                    vault_signer,
+                ),
+                refund_amount,
+            )?;
+            
+            user_stats.record_refund(refund_amount)?;
+            user_stats.exit(ctx.program_id)?;
+            emit!(user_stats.updated_event());
+            
+            emit!(PredictionRefunded {
+                round_id,
+                user: user_info.key(),
+                amount: refund_amount,
+                timestamp: clock.unix_timestamp,
+            });
+            
+            refunded_count = refunded_count
+                .checked_add(1)
+                .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+            total_refunded = total_refunded
+                .checked_add(refund_amount)
+                .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+        }
+        
+        // Return the prediction account's rent to its owner
+        prediction.close(user_info.clone())?;
+    }
+    
+    emit!(PredictionsRefunded {
+        round_id,
+        refunded_count,
+        total_refunded,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,22 @@
+// Revoke role instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::RevokeRole;
+use crate::events::RoleRevoked;
+use crate::state::Role;
+
+pub fn handler(ctx: Context<RevokeRole>, role: Role, member: Pubkey) -> Result<()> {
+    let roles = &mut ctx.accounts.roles;
+    let clock = Clock::get()?;
+
+    roles.revoke(role, member)?;
+
+    emit!(RoleRevoked {
+        role,
+        member,
+        revoked_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
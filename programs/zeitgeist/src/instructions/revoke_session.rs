@@ -0,0 +1,20 @@
+// Revoke session instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::RevokeSession;
+use crate::events::SessionRevoked;
+
+pub fn handler(ctx: Context<RevokeSession>, session_key: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    
+    emit!(SessionRevoked {
+        user: ctx.accounts.user.key(),
+        session_key,
+        spent: ctx.accounts.session_token.spent,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    // Note: Account closure handled automatically by `close = user` in context
+    
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// Sell AMM shares instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::SellAmmShares;
+use crate::events::AmmSharesSold;
+use crate::errors::SocialRouletteError;
+use crate::constants::AMM_VAULT_SEED;
+
+pub fn handler(
+    ctx: Context<SellAmmShares>,
+    market_id: u64,
+    outcome: u8,
+    return_amount: u64,
+    max_shares_in: u64,
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let position = &mut ctx.accounts.position;
+    let clock = Clock::get()?;
+
+    require!(
+        market.is_trading(clock.unix_timestamp),
+        SocialRouletteError::TradingClosed
+    );
+
+    require!(return_amount > 0, SocialRouletteError::InvalidPredictionAmount);
+
+    require!(
+        outcome < market.num_outcomes,
+        SocialRouletteError::InvalidOutcome
+    );
+
+    let shares = market.calc_sell_shares(outcome, return_amount)?;
+    require!(
+        shares <= max_shares_in,
+        SocialRouletteError::SlippageExceeded
+    );
+
+    position.remove_shares(outcome, shares)?;
+    market.apply_sell(outcome, return_amount, shares)?;
+    
+    // Proceeds take back the position's cost first; only the rest is profit
+    market.release_trader_collateral(position.release_cost(return_amount))?;
+
+    // Create PDA signer seeds for vault
+    let market_id_bytes = market_id.to_le_bytes();
+    let vault_seeds = &[
+        AMM_VAULT_SEED,
+        market_id_bytes.as_ref(),
+        &[ctx.bumps.vault],
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.user.to_account_info(),
+            },
+            vault_signer,
+        ),
+        return_amount,
+    )?;
+
+    emit!(AmmSharesSold {
+        market_id,
+        user: ctx.accounts.user.key(),
+        outcome,
+        amount: return_amount,
+        shares,
+        prices_bps: market.prices_bps()?,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
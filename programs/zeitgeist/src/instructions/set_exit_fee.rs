@@ -0,0 +1,29 @@
+// Set exit fee instruction
+
+use anchor_lang::prelude::*;
+use crate::constants::MAX_EXIT_FEE_BPS;
+use crate::contexts::SetExitFee;
+use crate::errors::SocialRouletteError;
+use crate::events::ExitFeeUpdated;
+
+pub fn handler(ctx: Context<SetExitFee>, exit_fee_bps: u16) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    let clock = Clock::get()?;
+
+    require!(
+        exit_fee_bps <= MAX_EXIT_FEE_BPS,
+        SocialRouletteError::InvalidExitFee
+    );
+
+    let old_exit_fee_bps = global_state.exit_fee_bps;
+    global_state.exit_fee_bps = exit_fee_bps;
+
+    emit!(ExitFeeUpdated {
+        old_exit_fee_bps,
+        new_exit_fee_bps: exit_fee_bps,
+        updated_by: ctx.accounts.treasurer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
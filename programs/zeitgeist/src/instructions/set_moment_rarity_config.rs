@@ -0,0 +1,36 @@
+// Set moment rarity config instruction
+
+use anchor_lang::prelude::*;
+use crate::constants::RARITY_TIER_COUNT;
+use crate::contexts::SetMomentRarityConfig;
+use crate::events::MomentRarityConfigUpdated;
+use crate::state::{MomentConfig, RarityTier};
+
+pub fn handler(
+    ctx: Context<SetMomentRarityConfig>,
+    rarity_tiers: [RarityTier; RARITY_TIER_COUNT],
+    early_bettor_window: i64,
+    comeback_loss_streak: u32,
+    largest_stake_bonus: bool,
+) -> Result<()> {
+    let moment_config = &mut ctx.accounts.moment_config;
+    let clock = Clock::get()?;
+
+    MomentConfig::validate_rarity_tiers(&rarity_tiers)?;
+
+    moment_config.rarity_tiers = rarity_tiers.clone();
+    moment_config.early_bettor_window = early_bettor_window.max(0);
+    moment_config.comeback_loss_streak = comeback_loss_streak;
+    moment_config.largest_stake_bonus = largest_stake_bonus;
+
+    emit!(MomentRarityConfigUpdated {
+        rarity_tiers,
+        early_bettor_window: moment_config.early_bettor_window,
+        comeback_loss_streak,
+        largest_stake_bonus,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// Set moment URI base instruction
+
+use anchor_lang::prelude::*;
+use crate::constants::MAX_MOMENT_URI_BASE_LEN;
+use crate::contexts::SetMomentUriBase;
+use crate::errors::SocialRouletteError;
+use crate::events::MomentUriBaseUpdated;
+
+pub fn handler(ctx: Context<SetMomentUriBase>, uri_base: String) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    let clock = Clock::get()?;
+
+    // Card URIs are `{base}/{card}`, so a trailing slash would double up
+    require!(
+        !uri_base.is_empty() && uri_base.len() <= MAX_MOMENT_URI_BASE_LEN && !uri_base.ends_with('/'),
+        SocialRouletteError::InvalidMomentUriBase
+    );
+
+    let old_uri_base = std::mem::replace(&mut global_state.moment_uri_base, uri_base.clone());
+
+    emit!(MomentUriBaseUpdated {
+        old_uri_base,
+        new_uri_base: uri_base,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+// Set round frozen instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::SetRoundFrozen;
+use crate::events::RoundFrozenUpdated;
+
+pub fn handler(ctx: Context<SetRoundFrozen>, round_id: u64, frozen: bool) -> Result<()> {
+    let round = &mut ctx.accounts.round;
+    let clock = Clock::get()?;
+
+    round.frozen = frozen;
+
+    emit!(RoundFrozenUpdated {
+        round_id,
+        frozen,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
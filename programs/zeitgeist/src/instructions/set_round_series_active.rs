@@ -0,0 +1,21 @@
+// Set round series active instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::SetRoundSeriesActive;
+use crate::events::RoundSeriesUpdated;
+
+pub fn handler(ctx: Context<SetRoundSeriesActive>, series_id: u64, active: bool) -> Result<()> {
+    let series = &mut ctx.accounts.series;
+    let clock = Clock::get()?;
+
+    series.active = active;
+
+    emit!(RoundSeriesUpdated {
+        series_id,
+        active,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
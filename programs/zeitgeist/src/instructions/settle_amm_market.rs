@@ -0,0 +1,37 @@
+// Settle AMM market instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::SettleAmmMarket;
+use crate::events::AmmMarketSettled;
+use crate::errors::SocialRouletteError;
+
+pub fn handler(ctx: Context<SettleAmmMarket>, market_id: u64) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    require!(
+        market.can_settle(clock.unix_timestamp),
+        SocialRouletteError::BettingStillActive
+    );
+
+    // Same oracle path as parimutuel rounds
+    let winning_outcome = crate::oracle::resolve_outcome(
+        &market.verification_method,
+        &ctx.accounts.oracle_data,
+        market.target_value,
+        market.num_outcomes,
+        clock.unix_timestamp,
+    )?;
+
+    market.settle(winning_outcome)?;
+
+    emit!(AmmMarketSettled {
+        market_id,
+        winning_outcome,
+        winning_shares_outstanding: market.outstanding_shares[winning_outcome as usize],
+        creator_payout: market.reserves[winning_outcome as usize],
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+// Settle parlay instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::SettleParlay;
+use crate::events::ParlaySettled;
+use crate::errors::SocialRouletteError;
+use crate::constants::PARLAY_VAULT_SEED;
+use crate::state::{LegResult, Round, RoundStatus};
+use crate::utils::calculate_winnings;
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettleParlay<'info>>,
+    parlay_id: u64,
+) -> Result<()> {
+    let parlay = &ctx.accounts.parlay;
+    let clock = Clock::get()?;
+    
+    require!(
+        ctx.remaining_accounts.len() == parlay.legs.len(),
+        SocialRouletteError::InvalidParlay
+    );
+    
+    // Every round must be final: settled legs win or lose, cancelled legs are void
+    let mut results = Vec::with_capacity(parlay.legs.len());
+    for (leg, round_info) in parlay.legs.iter().zip(ctx.remaining_accounts) {
+        let round = Account::<Round>::try_from(round_info)?;
+        require!(round.round_id == leg.round_id, SocialRouletteError::InvalidParlay);
+        require!(!round.frozen, SocialRouletteError::RoundFrozen);
+        
+        let result = match round.status {
+            // Closing multiplier: what one unit staked on the winner was actually paid
+            RoundStatus::Settled if round.winning_outcome == leg.outcome => LegResult::Won(
+                calculate_winnings(
+                    10000,
+                    round.winning_pool,
+                    round.total_pool,
+                    round.platform_fee_collected,
+                )?,
+            ),
+            RoundStatus::Settled => LegResult::Lost,
+            RoundStatus::Cancelled => LegResult::Void,
+            _ => return err!(SocialRouletteError::ParlayNotResolved),
+        };
+        results.push(result);
+    }
+    
+    let payout = parlay.settled_payout(&results)?;
+    ctx.accounts.parlay_pool.release(parlay.potential_payout, payout)?;
+    
+    if payout > 0 {
+        let vault_seeds = &[PARLAY_VAULT_SEED, &[ctx.bumps.vault]];
+        let vault_signer = &[&vault_seeds[..]];
+        
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.user.to_account_info(),
+                },
+                vault_signer,
+            ),
+            payout,
+        )?;
+    }
+    
+    emit!(ParlaySettled {
+        parlay_id,
+        user: parlay.user,
+        won: !results.contains(&LegResult::Lost),
+        void_legs: results.iter().filter(|result| **result == LegResult::Void).count() as u8,
+        payout,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    // Note: Account closure handled automatically by `close = user` in context
+    
+    Ok(())
+}
@@ -1,15 +1,14 @@
 use crate::constants::VAULT_SEED;
 use crate::contexts::SettleRound;
 use crate::errors::SocialRouletteError;
-use crate::events::RoundSettled;
-use crate::state::VerificationMethod;
+use crate::events::{RoundSettled, ScalarRoundResolved};
+use crate::state::RoundKind;
 use crate::utils::calculate_platform_fee;
 use anchor_lang::prelude::*;
 
 pub fn handler(
     ctx: Context<SettleRound>,
     round_id: u64,
-    winning_pool_amount: u64, // Still needed - calculated by backend
 ) -> Result<()> {
     let global_state = &ctx.accounts.global_state;
     let round = &mut ctx.accounts.round;
@@ -26,48 +25,26 @@ pub fn handler(
         SocialRouletteError::RoundAlreadySettled
     );
 
-    // ✅ NEW: Determine winning outcome based on verification method
-    let winning_outcome = match round.verification_method {
-        VerificationMethod::PythPrice => {
-            // Verify Pyth price feed
-            crate::oracle::pyth::verify_pyth_price(
-                round,
-                &ctx.accounts.oracle_data,
-                clock.unix_timestamp,
-            )?
-        }
-        VerificationMethod::SwitchboardVRF => {
-            // Verify Switchboard VRF randomness
-            crate::oracle::switchboard::verify_switchboard_vrf(
-                &ctx.accounts.oracle_data,
-                round.num_outcomes,
-            )?
-        }
-        VerificationMethod::OnChainData => {
-            // Verify on-chain data (block time, slot, etc.)
-            crate::oracle::onchain::verify_onchain_data(
-                &ctx.accounts.oracle_data,
-                round.target_value,
-                round.num_outcomes,
-            )?
-        }
-        VerificationMethod::TwitterAPI => {
-            // For Twitter API and other off-chain data, admin must provide outcome manually
-            // This verification method requires the old flow
-            return Err(SocialRouletteError::RequiresOffChainOracle.into());
-        }
+    // Scalar rounds record the Pyth value itself; payouts are derived from it at claim time
+    let resolved_value = match round.kind {
+        RoundKind::Scalar => Some(crate::oracle::read_pyth_price_cents(
+            &ctx.accounts.oracle_data,
+            clock.unix_timestamp,
+        )?),
+        RoundKind::Categorical => None,
     };
 
-    // Validate winning outcome is within range
-    require!(
-        winning_outcome < round.num_outcomes,
-        SocialRouletteError::InvalidOutcome
-    );
-
-    // ✅ NOTE: winning_pool_amount must still be calculated by backend
-    // Backend iterates through all prediction accounts to sum amounts where:
-    // prediction.outcome == winning_outcome
-    // This is too expensive to do on-chain due to compute limits
+    // ✅ NEW: Determine winning outcome based on verification method
+    let winning_outcome = match resolved_value {
+        Some(_) => crate::state::Round::UNSET_OUTCOME,
+        None => crate::oracle::resolve_outcome(
+            &round.verification_method,
+            &ctx.accounts.oracle_data,
+            round.target_value,
+            round.num_outcomes,
+            clock.unix_timestamp,
+        )?,
+    };
 
     // Calculate platform fee
     let platform_fee = calculate_platform_fee(round.total_pool, global_state.platform_fee_bps)?;
@@ -105,13 +82,25 @@ pub fn handler(
     }
     // Update round state
     round.platform_fee_collected = platform_fee;
-    round.set_winning_outcome(winning_outcome, winning_pool_amount)?;
+    match resolved_value {
+        Some(value) => {
+            round.settle_scalar(value)?;
+
+            emit!(ScalarRoundResolved {
+                round_id,
+                resolved_value: value,
+                lower_bound: round.lower_bound,
+                upper_bound: round.upper_bound,
+            });
+        }
+        None => round.set_winning_outcome(winning_outcome)?,
+    }
 
     emit!(RoundSettled {
         round_id,
-        winning_outcome,
+        winning_outcome: round.winning_outcome,
         total_pool: round.total_pool,
-        winning_pool: winning_pool_amount,
+        winning_pool: round.winning_pool,
         platform_fee,
         timestamp: clock.unix_timestamp,
     });
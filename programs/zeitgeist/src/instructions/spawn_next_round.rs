@@ -0,0 +1,98 @@
+// Spawn next round instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::SpawnNextRound;
+use crate::events::{RoundCreated, RoundSpawned};
+use crate::errors::SocialRouletteError;
+use crate::constants::MAX_OUTCOMES;
+use crate::state::{Round, RoundKind, RoundStatus};
+
+pub fn handler(ctx: Context<SpawnNextRound>, series_id: u64) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    let series = &mut ctx.accounts.series;
+    let round = &mut ctx.accounts.round;
+    let clock = Clock::get()?;
+
+    require!(series.active, SocialRouletteError::SeriesInactive);
+    require!(
+        series.can_spawn(clock.unix_timestamp),
+        SocialRouletteError::PreviousRoundNotEnded
+    );
+
+    let round_id = series.next_round_id;
+    let start_time = clock.unix_timestamp;
+    let end_time = start_time
+        .checked_add(series.cadence)
+        .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+    let betting_close_time = start_time
+        .checked_add(series.betting_window_duration)
+        .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+
+    // Initialize all round fields explicitly from the template
+    round.round_id = round_id;
+    round.creator = series.creator;
+    round.start_time = start_time;
+    round.betting_close_time = betting_close_time;
+    round.end_time = end_time;
+    round.total_pool = 0;
+    round.total_predictions = 0;
+    round.platform_fee_collected = 0;
+    round.num_outcomes = series.num_outcomes;
+    round.winning_outcome = Round::UNSET_OUTCOME;
+    round.tournament = None;
+    round.winning_pool = 0;
+    round.status = RoundStatus::Active;
+    round.bump = ctx.bumps.round;
+    round.question = series.question.clone();
+    round.verification_method = series.verification_method.clone();
+    round.target_value = series.target_value;
+    round.data_source = series.data_source;
+    round.oracle = series.oracle;
+    round.frozen = false;
+    round.version = Round::VERSION;
+    round.outcome_pools = [0; MAX_OUTCOMES as usize];
+    round.outcome_mints_created = 0;
+    round.token_collateral = 0;
+    round.kind = RoundKind::Categorical;
+    round.lower_bound = 0;
+    round.upper_bound = 0;
+    round.resolved_value = 0;
+    round.largest_stake = 0;
+    round.reserved = [0; 22];
+
+    // Initialize vault by transferring rent-exempt minimum
+    let rent = Rent::get()?;
+    if ctx.accounts.vault.lamports() == 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            rent.minimum_balance(0),
+        )?;
+    }
+
+    series.record_spawn(end_time)?;
+    global_state.increment_rounds()?;
+
+    emit!(RoundCreated {
+        round_id,
+        creator: series.creator,
+        start_time,
+        end_time,
+        num_outcomes: series.num_outcomes,
+        description: series.question.clone(),
+    });
+
+    emit!(RoundSpawned {
+        series_id,
+        round_id,
+        index: series.rounds_spawned - 1,
+        spawned_by: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+// Submit season score instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::SubmitSeasonScore;
+use crate::events::SeasonScoreSubmitted;
+use crate::errors::SocialRouletteError;
+
+pub fn handler(ctx: Context<SubmitSeasonScore>, season_id: u64, user: Pubkey) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let clock = Clock::get()?;
+    
+    require!(season.is_active(clock.unix_timestamp), SocialRouletteError::SeasonNotActive);
+    
+    let score = ctx.accounts.season_entry.season_score(&ctx.accounts.user_stats);
+    let rank = season.submit(score);
+    
+    emit!(SeasonScoreSubmitted {
+        season_id,
+        user,
+        rank,
+        net_profit: score.net_profit,
+        win_rate_bps: score.win_rate_bps,
+        volume: score.volume,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
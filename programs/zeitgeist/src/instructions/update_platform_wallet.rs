@@ -0,0 +1,22 @@
+// Update platform wallet instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::UpdatePlatformWallet;
+use crate::events::PlatformWalletUpdated;
+
+pub fn handler(ctx: Context<UpdatePlatformWallet>, new_wallet: Pubkey) -> Result<()> {
+    let global_state = &mut ctx.accounts.global_state;
+    let clock = Clock::get()?;
+
+    let old_wallet = global_state.platform_wallet;
+    global_state.platform_wallet = new_wallet;
+
+    emit!(PlatformWalletUpdated {
+        old_wallet,
+        new_wallet,
+        updated_by: ctx.accounts.treasurer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
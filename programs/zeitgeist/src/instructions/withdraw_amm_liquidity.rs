@@ -0,0 +1,53 @@
+// Withdraw AMM liquidity instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::WithdrawAmmLiquidity;
+use crate::events::AmmLiquidityWithdrawn;
+use crate::errors::SocialRouletteError;
+use crate::constants::AMM_VAULT_SEED;
+
+pub fn handler(ctx: Context<WithdrawAmmLiquidity>, market_id: u64) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let clock = Clock::get()?;
+
+    require!(
+        market.is_settled() || market.is_cancelled(),
+        SocialRouletteError::RoundNotSettled
+    );
+
+    // The pool's winning reserve (or what's left after traders' refunds) is
+    // the creator's share; traders' payouts stay fully backed in the vault
+    let amount = market.withdraw_liquidity()?;
+
+    if amount > 0 {
+        // Create PDA signer seeds for vault
+        let market_id_bytes = market_id.to_le_bytes();
+        let vault_seeds = &[
+            AMM_VAULT_SEED,
+            market_id_bytes.as_ref(),
+            &[ctx.bumps.vault],
+        ];
+        let vault_signer = &[&vault_seeds[..]];
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.creator.to_account_info(),
+                },
+                vault_signer,
+            ),
+            amount,
+        )?;
+    }
+
+    emit!(AmmLiquidityWithdrawn {
+        market_id,
+        creator: ctx.accounts.creator.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+// Withdraw balance instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::WithdrawBalance;
+use crate::events::BalanceWithdrawn;
+use crate::errors::SocialRouletteError;
+use crate::utils::transfer_from_program_account;
+
+pub fn handler(ctx: Context<WithdrawBalance>, amount: u64) -> Result<()> {
+    let balance = &mut ctx.accounts.balance;
+    let clock = Clock::get()?;
+    
+    require!(amount > 0, SocialRouletteError::InvalidPredictionAmount);
+    
+    balance.debit(amount)?;
+    balance.total_withdrawn = balance.total_withdrawn
+        .checked_add(amount)
+        .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+    
+    transfer_from_program_account(
+        &balance.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        amount,
+    )?;
+    
+    emit!(BalanceWithdrawn {
+        user: balance.user,
+        amount,
+        balance: balance.amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
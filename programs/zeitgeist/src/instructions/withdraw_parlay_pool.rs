@@ -0,0 +1,46 @@
+// Withdraw parlay pool instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::WithdrawParlayPool;
+use crate::events::ParlayPoolWithdrawn;
+use crate::errors::SocialRouletteError;
+use crate::constants::PARLAY_VAULT_SEED;
+
+pub fn handler(ctx: Context<WithdrawParlayPool>, amount: u64) -> Result<()> {
+    let parlay_pool = &ctx.accounts.parlay_pool;
+    let clock = Clock::get()?;
+    
+    require!(amount > 0, SocialRouletteError::InvalidPredictionAmount);
+    
+    // Open parlays' potential payouts must stay covered
+    let available = ctx.accounts.vault.lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0))
+        .saturating_sub(parlay_pool.total_liability);
+    require!(
+        amount <= available,
+        SocialRouletteError::InsufficientParlayLiquidity
+    );
+    
+    let vault_seeds = &[PARLAY_VAULT_SEED, &[ctx.bumps.vault]];
+    let vault_signer = &[&vault_seeds[..]];
+    
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.treasurer.to_account_info(),
+            },
+            vault_signer,
+        ),
+        amount,
+    )?;
+    
+    emit!(ParlayPoolWithdrawn {
+        treasurer: ctx.accounts.treasurer.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,96 @@
+// Withdraw prediction instruction
+
+use anchor_lang::prelude::*;
+use crate::contexts::WithdrawPrediction;
+use crate::events::PredictionWithdrawn;
+use crate::errors::SocialRouletteError;
+use crate::constants::*;
+use crate::utils::calculate_platform_fee;
+
+pub fn handler(
+    ctx: Context<WithdrawPrediction>,
+    round_id: u64,
+    outcome: u8,
+    amount: u64,
+) -> Result<()> {
+    let global_state = &ctx.accounts.global_state;
+    let round = &mut ctx.accounts.round;
+    let prediction = &mut ctx.accounts.prediction;
+    let user_stats = &mut ctx.accounts.user_stats;
+    let clock = Clock::get()?;
+    
+    // Withdrawals are only allowed while bets are still being taken
+    require!(
+        round.is_betting_active(clock.unix_timestamp),
+        SocialRouletteError::BettingEnded
+    );
+    
+    require!(amount > 0, SocialRouletteError::InvalidPredictionAmount);
+    
+    // Reduce the position; fails if `amount` exceeds the stake on `outcome`
+    let remaining_stake = prediction.remove_stake(outcome, amount)?;
+    
+    // Don't leave dust positions behind
+    require!(
+        remaining_stake == 0 || remaining_stake >= MIN_PREDICTION_AMOUNT,
+        SocialRouletteError::InvalidPredictionAmount
+    );
+    
+    let closed_position = prediction.amount == 0;
+    
+    // Exit fee stays in the vault and the pool
+    let exit_fee = calculate_platform_fee(amount, global_state.exit_fee_bps)?;
+    let payout = amount
+        .checked_sub(exit_fee)
+        .ok_or(SocialRouletteError::ArithmeticUnderflow)?;
+    
+    require!(
+        ctx.accounts.vault.lamports() >= payout,
+        SocialRouletteError::InsufficientVaultBalance
+    );
+    
+    // Create PDA signer seeds for vault
+    let round_id_bytes = round_id.to_le_bytes();
+    let vault_seeds = &[
+        VAULT_SEED,
+        round_id_bytes.as_ref(),
+        &[ctx.bumps.vault],
+    ];
+    let vault_signer = &[&vault_seeds[..]];
+    
+    // CPI to System Program to transfer from vault to user
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.user.to_account_info(),
+            },
+            vault_signer,
+        ),
+        payout,
+    )?;
+    
+    round.remove_prediction(amount, exit_fee, outcome, closed_position)?;
+    user_stats.record_withdrawal(amount, exit_fee, closed_position)?;
+    emit!(user_stats.updated_event());
+    
+    emit!(PredictionWithdrawn {
+        round_id,
+        user: ctx.accounts.user.key(),
+        outcome,
+        amount,
+        exit_fee,
+        remaining_stake,
+        outcome_pool: round.outcome_pools[outcome as usize],
+        total_pool: round.total_pool,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    // Fully exited positions return their rent to the user
+    if closed_position {
+        prediction.close(ctx.accounts.user.to_account_info())?;
+    }
+    
+    Ok(())
+}
@@ -11,6 +11,7 @@ pub mod instructions;
 pub mod state;
 pub mod utils;
 pub mod oracle;  // Add this line
+pub mod moment;
 
 
 // Re-export all contexts at crate root for Anchor macro
@@ -21,12 +22,13 @@ pub mod zeitgeist {
     use super::*;
 
     /// Initialize the global state (one-time setup)
-
     pub fn initialize(ctx: Context<Initialize>, platform_wallet: Pubkey) -> Result<()> {
         instructions::initialize::handler(ctx, platform_wallet)
     }
 
-    /// Create a new prediction round
+    /// Create a new prediction round (round creator only). `round_id` must be
+    /// below `AUTO_ROUND_ID_BASE`; higher ids belong to auto and series rounds.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_round(
         ctx: Context<CreateRound>,
         round_id: u64,
@@ -54,36 +56,64 @@ pub mod zeitgeist {
             betting_window_duration, // ← ADD
         )
     }
-    /// Place a prediction on a round
-    pub fn place_prediction(
-        ctx: Context<PlacePrediction>,
+    /// Place a prediction on a round, optionally rejecting it if the post-bet
+    /// payout multiplier for `outcome` falls below `min_implied_multiplier_bps`.
+    /// A held moment card, proven against `card_root`, applies its holder perk
+    /// to this bet's stake (one perk per position).
+    pub fn place_prediction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PlacePrediction<'info>>,
         round_id: u64,
         outcome: u8,
         amount: u64,
+        min_implied_multiplier_bps: Option<u64>,
+        card_root: Option<[u8; 32]>,
     ) -> Result<()> {
-        instructions::place_prediction::handler(ctx, round_id, outcome, amount)
+        instructions::place_prediction::handler(
+            ctx,
+            round_id,
+            outcome,
+            amount,
+            min_implied_multiplier_bps,
+            card_root,
+        )
     }
 
-    /// Close betting for a round (after end_time)
+    /// Return the current per-outcome payout multipliers after fees, in basis
+    /// points (view-only; call via simulation)
+    pub fn get_odds(ctx: Context<GetOdds>, round_id: u64) -> Result<Vec<u64>> {
+        instructions::get_odds::handler(ctx, round_id)
+    }
+
+    /// Withdraw some or all of a stake while betting is open, paying the exit fee
+    pub fn withdraw_prediction(
+        ctx: Context<WithdrawPrediction>,
+        round_id: u64,
+        outcome: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_prediction::handler(ctx, round_id, outcome, amount)
+    }
+
+    /// Close betting for a round (settler only)
     pub fn close_betting(ctx: Context<CloseBetting>, round_id: u64) -> Result<()> {
         instructions::close_betting::handler(ctx, round_id)
     }
 
-    /// Settle a round with the winning outcome (admin only)
-  /// Settle a round with the winning outcome (admin only)
+  /// Settle a round with the winning outcome (settler only). The winning pool
+  /// is the on-chain stake on that outcome, so it always matches what claims pay.
 pub fn settle_round(
     ctx: Context<SettleRound>,
     round_id: u64,
-    winning_pool_amount: u64,  // ← Only this parameter, not winning_outcome
 ) -> Result<()> {
-    instructions::settle_round::handler(ctx, round_id, winning_pool_amount)
+    instructions::settle_round::handler(ctx, round_id)
 }
-    /// Claim winnings from a settled round
+    /// Claim winnings from a settled round. The prediction stays open, marked
+    /// claimed, until its moment card is minted or it is closed.
     pub fn claim_winnings(ctx: Context<ClaimWinnings>, round_id: u64) -> Result<()> {
         instructions::claim_winnings::handler(ctx, round_id)
     }
 
-    /// Create a new tournament
+    /// Create a new tournament (round creator only)
     pub fn create_tournament(
         ctx: Context<CreateTournament>,
         tournament_id: u64,
@@ -100,7 +130,7 @@ pub fn settle_round(
         )
     }
 
-    /// Emergency cancel a round (admin only)
+    /// Emergency cancel a round (settler only)
     pub fn emergency_cancel(
         ctx: Context<EmergencyCancel>,
         round_id: u64,
@@ -114,16 +144,502 @@ pub fn settle_round(
         instructions::refund_prediction::handler(ctx, round_id)
     }
 
-    pub fn pause_program(ctx: Context<PauseProgram>) -> Result<()> {
-        instructions::pause_program::handler(ctx)
+    /// Pause the instruction families set in `flags` (pauser only)
+    pub fn pause_program(ctx: Context<PauseProgram>, flags: u8) -> Result<()> {
+        instructions::pause_program::handler(ctx, flags)
     }
 
+    /// Unpause the instruction families set in `flags` (pauser only)
+    pub fn unpause_program(ctx: Context<UnPauseProgram>, flags: u8) -> Result<()> {
+        instructions::unpause_program::handler(ctx, flags)
+    }
+
+    /// Freeze or unfreeze all activity on a single round (pauser only)
+    pub fn set_round_frozen(
+        ctx: Context<SetRoundFrozen>,
+        round_id: u64,
+        frozen: bool,
+    ) -> Result<()> {
+        instructions::set_round_frozen::handler(ctx, round_id, frozen)
+    }
+
+    /// Upgrade the global state to the latest layout (admin only, run first)
+    pub fn migrate_global_state(ctx: Context<MigrateGlobalState>) -> Result<()> {
+        instructions::migrate_global_state::handler(ctx)
+    }
+
+    /// Upgrade a round to the latest layout (admin only)
+    pub fn migrate_round(ctx: Context<MigrateRound>, round_id: u64) -> Result<()> {
+        instructions::migrate_round::handler(ctx, round_id)
+    }
+
+    /// Upgrade a prediction to the latest layout (admin only)
+    pub fn migrate_prediction(
+        ctx: Context<MigratePrediction>,
+        round_id: u64,
+        user: Pubkey,
+    ) -> Result<()> {
+        instructions::migrate_prediction::handler(ctx, round_id, user)
+    }
 
-    pub fn unpause_program(ctx: Context<UnPauseProgram>) -> Result<()> {
-        instructions::unpause_program::handler(ctx)
+    /// Upgrade a user's stats to the latest layout (admin only)
+    pub fn migrate_user_stats(ctx: Context<MigrateUserStats>, user: Pubkey) -> Result<()> {
+        instructions::migrate_user_stats::handler(ctx, user)
+    }
+
+    /// Upgrade a tournament to the latest layout (admin only)
+    pub fn migrate_tournament(ctx: Context<MigrateTournament>, tournament_id: u64) -> Result<()> {
+        instructions::migrate_tournament::handler(ctx, tournament_id)
+    }
+
+    /// Create a recurring round template (round creator only)
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_round_series(
+        ctx: Context<CreateRoundSeries>,
+        series_id: u64,
+        description: String,
+        num_outcomes: u8,
+        verification_method: state::VerificationMethod,
+        target_value: i64,
+        data_source: Pubkey,
+        oracle: Pubkey,
+        cadence: i64,
+        betting_window_duration: i64,
+    ) -> Result<()> {
+        instructions::create_round_series::handler(
+            ctx,
+            series_id,
+            description,
+            num_outcomes,
+            verification_method,
+            target_value,
+            data_source,
+            oracle,
+            cadence,
+            betting_window_duration,
+        )
+    }
+
+    /// Create the next round of a series once the previous one has ended (permissionless)
+    pub fn spawn_next_round(ctx: Context<SpawnNextRound>, series_id: u64) -> Result<()> {
+        instructions::spawn_next_round::handler(ctx, series_id)
+    }
+
+    /// Stop or resume spawning rounds for a series (round creator only)
+    pub fn set_round_series_active(
+        ctx: Context<SetRoundSeriesActive>,
+        series_id: u64,
+        active: bool,
+    ) -> Result<()> {
+        instructions::set_round_series_active::handler(ctx, series_id, active)
+    }
+
+    /// Create a round whose id is assigned from the round counter, in a range
+    /// manual ids can't use (round creator only). Returns the assigned id;
+    /// `client_nonce` makes retries idempotent.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_round_auto(
+        ctx: Context<CreateRoundAuto>,
+        client_nonce: u64,
+        start_time: i64,
+        end_time: i64,
+        num_outcomes: u8,
+        description: String,
+        verification_method: state::VerificationMethod,
+        target_value: i64,
+        data_source: Pubkey,
+        oracle: Pubkey,
+        betting_window_duration: i64,
+    ) -> Result<u64> {
+        instructions::create_round_auto::handler(
+            ctx,
+            client_nonce,
+            start_time,
+            end_time,
+            num_outcomes,
+            description,
+            verification_method,
+            target_value,
+            data_source,
+            oracle,
+            betting_window_duration,
+        )
     }
 
      pub fn mint_moment_card(ctx: Context<MintMomentCard>, round_id: u64) -> Result<()> {
         instructions::mint_moment_card::handler(ctx, round_id)
     }
+
+    /// Create the roles account and grant every role to the admin (admin only)
+    pub fn initialize_roles(ctx: Context<InitializeRoles>) -> Result<()> {
+        instructions::initialize_roles::handler(ctx)
+    }
+
+    /// Grant a role to a key (admin only)
+    pub fn grant_role(ctx: Context<GrantRole>, role: state::Role, member: Pubkey) -> Result<()> {
+        instructions::grant_role::handler(ctx, role, member)
+    }
+
+    /// Revoke a role from a key (admin only)
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: state::Role, member: Pubkey) -> Result<()> {
+        instructions::revoke_role::handler(ctx, role, member)
+    }
+
+    /// Change the wallet that receives platform fees (treasurer only)
+    pub fn update_platform_wallet(
+        ctx: Context<UpdatePlatformWallet>,
+        new_wallet: Pubkey,
+    ) -> Result<()> {
+        instructions::update_platform_wallet::handler(ctx, new_wallet)
+    }
+
+    /// Change the early withdrawal fee (treasurer only)
+    pub fn set_exit_fee(ctx: Context<SetExitFee>, exit_fee_bps: u16) -> Result<()> {
+        instructions::set_exit_fee::handler(ctx, exit_fee_bps)
+    }
+
+    /// Create an AMM market funded by the creator's liquidity (round creator only)
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_amm_market(
+        ctx: Context<CreateAmmMarket>,
+        market_id: u64,
+        description: String,
+        num_outcomes: u8,
+        verification_method: state::VerificationMethod,
+        target_value: i64,
+        data_source: Pubkey,
+        oracle: Pubkey,
+        trading_close_time: i64,
+        end_time: i64,
+        initial_liquidity: u64,
+    ) -> Result<()> {
+        instructions::create_amm_market::handler(
+            ctx,
+            market_id,
+            description,
+            num_outcomes,
+            verification_method,
+            target_value,
+            data_source,
+            oracle,
+            trading_close_time,
+            end_time,
+            initial_liquidity,
+        )
+    }
+
+    /// Buy outcome shares from an AMM market at the current price
+    pub fn buy_amm_shares(
+        ctx: Context<BuyAmmShares>,
+        market_id: u64,
+        outcome: u8,
+        amount: u64,
+        min_shares_out: u64,
+    ) -> Result<()> {
+        instructions::buy_amm_shares::handler(ctx, market_id, outcome, amount, min_shares_out)
+    }
+
+    /// Sell outcome shares back to an AMM market for `return_amount` lamports
+    pub fn sell_amm_shares(
+        ctx: Context<SellAmmShares>,
+        market_id: u64,
+        outcome: u8,
+        return_amount: u64,
+        max_shares_in: u64,
+    ) -> Result<()> {
+        instructions::sell_amm_shares::handler(ctx, market_id, outcome, return_amount, max_shares_in)
+    }
+
+    /// Settle an AMM market from its oracle (settler only)
+    pub fn settle_amm_market(ctx: Context<SettleAmmMarket>, market_id: u64) -> Result<()> {
+        instructions::settle_amm_market::handler(ctx, market_id)
+    }
+
+    /// Redeem winning AMM shares 1:1 for lamports, or refund the collateral
+    /// paid for the position if the market was cancelled
+    pub fn redeem_amm_shares(ctx: Context<RedeemAmmShares>, market_id: u64) -> Result<()> {
+        instructions::redeem_amm_shares::handler(ctx, market_id)
+    }
+
+    /// Pay the pool's remaining winning reserve (or, if cancelled, the collateral
+    /// left after traders' refunds) to the market creator
+    pub fn withdraw_amm_liquidity(ctx: Context<WithdrawAmmLiquidity>, market_id: u64) -> Result<()> {
+        instructions::withdraw_amm_liquidity::handler(ctx, market_id)
+    }
+
+    /// Create the SPL token mint for one outcome of a round (round creator only).
+    /// Mints must be created for outcomes 0..num_outcomes in order.
+    pub fn create_outcome_mint(ctx: Context<CreateOutcomeMint>, round_id: u64, outcome: u8) -> Result<()> {
+        instructions::create_outcome_mint::handler(ctx, round_id, outcome)
+    }
+
+    /// Stake lamports for one token of every outcome per lamport
+    pub fn mint_complete_set<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintCompleteSet<'info>>,
+        round_id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::mint_complete_set::handler(ctx, round_id, amount)
+    }
+
+    /// Burn one token of every outcome per lamport returned
+    pub fn burn_complete_set<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BurnCompleteSet<'info>>,
+        round_id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::burn_complete_set::handler(ctx, round_id, amount)
+    }
+
+    /// Redeem winning outcome tokens pro rata from the round's token collateral
+    pub fn redeem_outcome_tokens(ctx: Context<RedeemOutcomeTokens>, round_id: u64, amount: u64) -> Result<()> {
+        instructions::redeem_outcome_tokens::handler(ctx, round_id, amount)
+    }
+
+    /// Create a scalar round settled from a Pyth price (round creator only).
+    /// Bet outcome 0 for short and 1 for long; both sides are paid linearly
+    /// by where the price lands between `lower_bound` and `upper_bound` (in cents).
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_scalar_round(
+        ctx: Context<CreateRound>,
+        round_id: u64,
+        start_time: i64,
+        end_time: i64,
+        description: String,
+        data_source: Pubkey,
+        oracle: Pubkey,
+        betting_window_duration: i64,
+        lower_bound: i64,
+        upper_bound: i64,
+    ) -> Result<()> {
+        instructions::create_scalar_round::handler(
+            ctx,
+            round_id,
+            start_time,
+            end_time,
+            description,
+            data_source,
+            oracle,
+            betting_window_duration,
+            lower_bound,
+            upper_bound,
+        )
+    }
+
+    /// Add lamports to the house bankroll backing parlays (treasurer only)
+    pub fn fund_parlay_pool(ctx: Context<FundParlayPool>, amount: u64) -> Result<()> {
+        instructions::fund_parlay_pool::handler(ctx, amount)
+    }
+
+    /// Withdraw bankroll not reserved for open parlays (treasurer only)
+    pub fn withdraw_parlay_pool(ctx: Context<WithdrawParlayPool>, amount: u64) -> Result<()> {
+        instructions::withdraw_parlay_pool::handler(ctx, amount)
+    }
+
+    /// Place a parlay across several rounds at the product of their current odds,
+    /// each leg capped and paid at no more than its round's closing odds.
+    /// Fails if the quoted payout is below `min_payout`.
+    pub fn place_parlay<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PlaceParlay<'info>>,
+        parlay_id: u64,
+        picks: Vec<state::ParlayPick>,
+        amount: u64,
+        min_payout: u64,
+    ) -> Result<()> {
+        instructions::place_parlay::handler(ctx, parlay_id, picks, amount, min_payout)
+    }
+
+    /// Settle a parlay once all its rounds are settled or cancelled (permissionless).
+    /// Legs on cancelled rounds are void; if all legs are void the stake is refunded.
+    pub fn settle_parlay<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleParlay<'info>>,
+        parlay_id: u64,
+    ) -> Result<()> {
+        instructions::settle_parlay::handler(ctx, parlay_id)
+    }
+
+    /// Authorize a session key to place predictions for the signer until
+    /// `duration` seconds from now, up to `spend_cap` lamports, optionally
+    /// sending it `top_up` lamports to bet and pay fees with
+    pub fn create_session(
+        ctx: Context<CreateSession>,
+        session_key: Pubkey,
+        duration: i64,
+        spend_cap: u64,
+        top_up: u64,
+    ) -> Result<()> {
+        instructions::create_session::handler(ctx, session_key, duration, spend_cap, top_up)
+    }
+
+    /// Revoke a session key before it expires
+    pub fn revoke_session(ctx: Context<RevokeSession>, session_key: Pubkey) -> Result<()> {
+        instructions::revoke_session::handler(ctx, session_key)
+    }
+
+    /// Deposit lamports into the signer's custodial balance. Passing the balance
+    /// to place_prediction, claim_winnings or refund_prediction bets from or
+    /// pays into it instead of the wallet.
+    pub fn deposit_balance(ctx: Context<DepositBalance>, amount: u64) -> Result<()> {
+        instructions::deposit_balance::handler(ctx, amount)
+    }
+
+    /// Withdraw lamports from the signer's custodial balance to their wallet
+    pub fn withdraw_balance(ctx: Context<WithdrawBalance>, amount: u64) -> Result<()> {
+        instructions::withdraw_balance::handler(ctx, amount)
+    }
+
+    /// Pay out winners of a settled round in bulk (permissionless crank).
+    /// Claimed positions in the batch are skipped and losing ones resolved;
+    /// prediction accounts stay open for their owners to mint or close.
+    pub fn distribute_winnings<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeWinnings<'info>>,
+        round_id: u64,
+    ) -> Result<()> {
+        instructions::distribute_winnings::handler(ctx, round_id)
+    }
+
+    /// Refund stakes of a cancelled round in bulk and close the predictions
+    /// (permissionless crank)
+    pub fn refund_predictions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RefundPredictions<'info>>,
+        round_id: u64,
+    ) -> Result<()> {
+        instructions::refund_predictions::handler(ctx, round_id)
+    }
+
+    /// Resolve the signer's losing prediction on a settled round, recording the
+    /// loss in their stats and returning the account's rent
+    pub fn record_loss(ctx: Context<RecordLoss>, round_id: u64) -> Result<()> {
+        instructions::record_loss::handler(ctx, round_id)
+    }
+
+    /// Mark a tournament completed and credit the winner (settler only)
+    pub fn complete_tournament(
+        ctx: Context<CompleteTournament>,
+        tournament_id: u64,
+        winner: Pubkey,
+    ) -> Result<()> {
+        instructions::complete_tournament::handler(ctx, tournament_id, winner)
+    }
+
+    /// Open a leaderboard season funded with `reward_pool` lamports from the
+    /// treasury (treasurer only). A `global` season ranks lifetime stats.
+    pub fn create_season(
+        ctx: Context<CreateSeason>,
+        season_id: u64,
+        start_time: i64,
+        end_time: i64,
+        global: bool,
+        reward_pool: u64,
+    ) -> Result<()> {
+        instructions::create_season::handler(ctx, season_id, start_time, end_time, global, reward_pool)
+    }
+
+    /// Enter a started season, snapshotting the signer's stats as their baseline
+    pub fn join_season(ctx: Context<JoinSeason>, season_id: u64) -> Result<()> {
+        instructions::join_season::handler(ctx, season_id)
+    }
+
+    /// Rank a joined player on their stats change since joining, with unresolved
+    /// stakes counted as lost (permissionless, so anyone can refresh a stale entry)
+    pub fn submit_season_score(
+        ctx: Context<SubmitSeasonScore>,
+        season_id: u64,
+        user: Pubkey,
+    ) -> Result<()> {
+        instructions::submit_season_score::handler(ctx, season_id, user)
+    }
+
+    /// Finalize an ended season's leaderboard and return unallocated rewards
+    /// to the treasury (permissionless crank)
+    pub fn close_season(ctx: Context<CloseSeason>, season_id: u64) -> Result<()> {
+        instructions::close_season::handler(ctx, season_id)
+    }
+
+    /// Set the base URI moment card metadata is served from (admin only)
+    pub fn set_moment_uri_base(ctx: Context<SetMomentUriBase>, uri_base: String) -> Result<()> {
+        instructions::set_moment_uri_base::handler(ctx, uri_base)
+    }
+
+    /// Create the moment card tree with the program's moment authority PDA as
+    /// tree creator and record the official collection (admin only)
+    pub fn init_moment_tree(
+        ctx: Context<InitMomentTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        instructions::init_moment_tree::handler(ctx, max_depth, max_buffer_size)
+    }
+
+    /// Set the moment card rarity tiers and modifiers (admin only)
+    pub fn set_moment_rarity_config(
+        ctx: Context<SetMomentRarityConfig>,
+        rarity_tiers: [state::RarityTier; constants::RARITY_TIER_COUNT],
+        early_bettor_window: i64,
+        comeback_loss_streak: u32,
+        largest_stake_bonus: bool,
+    ) -> Result<()> {
+        instructions::set_moment_rarity_config::handler(
+            ctx,
+            rarity_tiers,
+            early_bettor_window,
+            comeback_loss_streak,
+            largest_stake_bonus,
+        )
+    }
+
+    /// Burn `MOMENT_COMBINE_COUNT` cards of one tier held by the signer and
+    /// mint one card of the next tier
+    pub fn combine_moment_cards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CombineMomentCards<'info>>,
+        roots: Vec<[u8; 32]>,
+        proof_len: u8,
+    ) -> Result<()> {
+        instructions::combine_moment_cards::handler(ctx, roots, proof_len)
+    }
+
+    /// Evolve a held card one tier by presenting held cards recording wins in later rounds
+    pub fn evolve_moment_card<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EvolveMomentCard<'info>>,
+        roots: Vec<[u8; 32]>,
+        proof_len: u8,
+    ) -> Result<()> {
+        instructions::evolve_moment_card::handler(ctx, roots, proof_len)
+    }
+
+    /// Fund the vault paying moment card holder perks (Treasurer)
+    pub fn fund_perk_vault(ctx: Context<FundPerkVault>, amount: u64) -> Result<()> {
+        instructions::fund_perk_vault::handler(ctx, amount)
+    }
+
+    /// Cancel an unsettled AMM market so traders and the creator can be refunded
+    /// (settler, or anyone once the settlement window has passed)
+    pub fn cancel_amm_market(
+        ctx: Context<CancelAmmMarket>,
+        market_id: u64,
+        reason: String,
+    ) -> Result<()> {
+        instructions::cancel_amm_market::handler(ctx, market_id, reason)
+    }
+
+    /// Redeem tokens of one outcome of a cancelled round for their share of
+    /// the round's token collateral
+    pub fn refund_outcome_tokens(
+        ctx: Context<RefundOutcomeTokens>,
+        round_id: u64,
+        outcome: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::refund_outcome_tokens::handler(ctx, round_id, outcome, amount)
+    }
+
+    /// Close a prediction already claimed or resolved by a crank, returning its rent
+    pub fn close_prediction(ctx: Context<ClosePrediction>, round_id: u64) -> Result<()> {
+        instructions::close_prediction::handler(ctx, round_id)
+    }
+
+    /// Pay the signer their reward for their rank on a closed season's leaderboard
+    pub fn claim_season_reward(ctx: Context<ClaimSeasonReward>, season_id: u64) -> Result<()> {
+        instructions::claim_season_reward::handler(ctx, season_id)
+    }
 }
@@ -0,0 +1,250 @@
+// Moment card cNFT helpers shared by minting, upgrades and holder perks
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+use mpl_bubblegum::{
+    accounts::TreeConfig,
+    hash::{hash_creators, hash_metadata},
+    instructions::{BurnCpiBuilder, MintToCollectionV1CpiBuilder},
+    programs::SPL_ACCOUNT_COMPRESSION_ID,
+    types::{Collection, LeafSchema, MetadataArgs, TokenProgramVersion, TokenStandard},
+    utils::get_asset_id,
+};
+use crate::constants::{MOMENT_AUTHORITY_SEED, PERK_VAULT_SEED, VERIFY_LEAF_DISCRIMINATOR};
+use crate::errors::SocialRouletteError;
+use crate::state::MomentCard;
+
+/// Bubblegum tree accounts for minting into or burning from a tree
+pub struct MomentTree<'a, 'info> {
+    pub bubblegum_program: &'a AccountInfo<'info>,
+    pub tree_authority: &'a AccountInfo<'info>,
+    pub merkle_tree: &'a AccountInfo<'info>,
+    pub log_wrapper: &'a AccountInfo<'info>,
+    pub compression_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+/// The official collection and the PDA that signs for it
+pub struct MomentCollection<'a, 'info> {
+    pub moment_authority: &'a AccountInfo<'info>,
+    pub authority_bump: u8,
+    pub collection_mint: &'a AccountInfo<'info>,
+    pub collection_metadata: &'a AccountInfo<'info>,
+    pub collection_edition: &'a AccountInfo<'info>,
+    pub bubblegum_signer: &'a AccountInfo<'info>,
+    pub token_metadata_program: &'a AccountInfo<'info>,
+}
+
+/// Leaf identity of a freshly minted card
+pub struct MintedCard {
+    pub asset_id: Pubkey,
+    pub leaf_index: u64,
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+}
+
+/// Mint a card for `card` (its record PDA) to `owner` as a verified collection
+/// item. Its URI is `{uri_base}/{card}`, so it renders the record on-chain.
+pub fn mint_card<'info>(
+    tree: &MomentTree<'_, 'info>,
+    collection: &MomentCollection<'_, 'info>,
+    owner: &AccountInfo<'info>,
+    card: Pubkey,
+    round_id: u64,
+    uri_base: &str,
+) -> Result<MintedCard> {
+    // The next leaf index is the tree's mint count, which fixes the card's asset id
+    require_keys_eq!(
+        *tree.tree_authority.owner,
+        mpl_bubblegum::ID,
+        SocialRouletteError::InvalidMomentTree
+    );
+    let leaf_index = TreeConfig::from_bytes(&tree.tree_authority.try_borrow_data()?)
+        .map_err(|_| SocialRouletteError::InvalidMomentTree)?
+        .num_minted;
+    
+    let mut metadata = MetadataArgs {
+        name: format!("Zeitgeist Round #{}", round_id),
+        symbol: "ZGST".to_string(),
+        uri: format!("{}/{}", uri_base, card),
+        seller_fee_basis_points: 0,
+        primary_sale_happened: true,
+        is_mutable: false,
+        edition_nonce: None,
+        token_standard: Some(TokenStandard::NonFungible),
+        // Bubblegum verifies the collection as part of MintToCollectionV1
+        collection: Some(Collection {
+            verified: false,
+            key: collection.collection_mint.key(),
+        }),
+        uses: None,
+        token_program_version: TokenProgramVersion::Original,
+        creators: vec![],
+    };
+    
+    let authority_seeds = &[
+        MOMENT_AUTHORITY_SEED,
+        &[collection.authority_bump],
+    ];
+    let authority_signer = &[&authority_seeds[..]];
+    
+    // The moment authority PDA signs as both tree creator and collection authority
+    MintToCollectionV1CpiBuilder::new(tree.bubblegum_program)
+        .tree_config(tree.tree_authority)
+        .leaf_owner(owner)
+        .leaf_delegate(owner)
+        .merkle_tree(tree.merkle_tree)
+        .payer(owner)
+        .tree_creator_or_delegate(collection.moment_authority)
+        .collection_authority(collection.moment_authority)
+        .collection_authority_record_pda(None)
+        .collection_mint(collection.collection_mint)
+        .collection_metadata(collection.collection_metadata)
+        .collection_edition(collection.collection_edition)
+        .bubblegum_signer(collection.bubblegum_signer)
+        .log_wrapper(tree.log_wrapper)
+        .compression_program(tree.compression_program)
+        .token_metadata_program(collection.token_metadata_program)
+        .system_program(tree.system_program)
+        .metadata(metadata.clone())
+        .invoke_signed(authority_signer)?;
+    
+    // The leaf commits to the metadata as stored, with the collection verified
+    if let Some(collection) = metadata.collection.as_mut() {
+        collection.verified = true;
+    }
+    let data_hash = hash_metadata(&metadata).map_err(|_| SocialRouletteError::InvalidMomentCard)?;
+    
+    Ok(MintedCard {
+        asset_id: get_asset_id(&tree.merkle_tree.key(), leaf_index),
+        leaf_index,
+        data_hash,
+        creator_hash: hash_creators(&metadata.creators),
+    })
+}
+
+/// Burn `card` held (and not delegated) by `owner`. Bubblegum checks `proof`
+/// against `root` before replacing the leaf.
+pub fn burn_card<'info>(
+    tree: &MomentTree<'_, 'info>,
+    owner: &AccountInfo<'info>,
+    card: &MomentCard,
+    root: [u8; 32],
+    proof: &[AccountInfo<'info>],
+) -> Result<()> {
+    let proof_accounts: Vec<_> = proof.iter().map(|node| (node, false, false)).collect();
+    
+    BurnCpiBuilder::new(tree.bubblegum_program)
+        .tree_config(tree.tree_authority)
+        .leaf_owner(owner, true)
+        .leaf_delegate(owner, false)
+        .merkle_tree(tree.merkle_tree)
+        .log_wrapper(tree.log_wrapper)
+        .compression_program(tree.compression_program)
+        .system_program(tree.system_program)
+        .root(root)
+        .data_hash(card.data_hash)
+        .creator_hash(card.creator_hash)
+        .nonce(card.leaf_index)
+        .index(leaf_position(card)?)
+        .add_remaining_accounts(&proof_accounts)
+        .invoke()?;
+    
+    Ok(())
+}
+
+/// Prove `owner` currently holds `card` (undelegated) without touching it, by
+/// verifying its leaf against `root` in the compression program
+pub fn verify_card_owner<'info>(
+    compression_program: &AccountInfo<'info>,
+    merkle_tree: &AccountInfo<'info>,
+    owner: Pubkey,
+    card: &MomentCard,
+    root: [u8; 32],
+    proof: &[AccountInfo<'info>],
+) -> Result<()> {
+    require_keys_eq!(
+        compression_program.key(),
+        SPL_ACCOUNT_COMPRESSION_ID,
+        SocialRouletteError::InvalidMomentTree
+    );
+    require_keys_eq!(
+        merkle_tree.key(),
+        card.merkle_tree,
+        SocialRouletteError::InvalidMomentTree
+    );
+    
+    let leaf = LeafSchema::V1 {
+        id: card.asset_id,
+        owner,
+        delegate: owner,
+        nonce: card.leaf_index,
+        data_hash: card.data_hash,
+        creator_hash: card.creator_hash,
+    }
+    .hash();
+    
+    let mut data = Vec::with_capacity(8 + 32 + 32 + 4);
+    data.extend_from_slice(&VERIFY_LEAF_DISCRIMINATOR);
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&leaf);
+    data.extend_from_slice(&leaf_position(card)?.to_le_bytes());
+    
+    let mut accounts = vec![AccountMeta::new_readonly(merkle_tree.key(), false)];
+    accounts.extend(proof.iter().map(|node| AccountMeta::new_readonly(node.key(), false)));
+    
+    let mut account_infos = vec![merkle_tree.clone()];
+    account_infos.extend(proof.iter().cloned());
+    account_infos.push(compression_program.clone());
+    
+    invoke(
+        &Instruction {
+            program_id: SPL_ACCOUNT_COMPRESSION_ID,
+            accounts,
+            data,
+        },
+        &account_infos,
+    )?;
+    
+    Ok(())
+}
+
+/// Pay a holder perk bonus from the perk vault, capped at what the vault holds
+/// above rent. Returns the amount paid.
+pub fn pay_perk_bonus<'info>(
+    perk_vault: &AccountInfo<'info>,
+    vault_bump: u8,
+    recipient: AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    bonus: u64,
+) -> Result<u64> {
+    let available = perk_vault
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    let paid = bonus.min(available);
+    if paid == 0 {
+        return Ok(0);
+    }
+    
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Transfer {
+                from: perk_vault.clone(),
+                to: recipient,
+            },
+            &[&[PERK_VAULT_SEED, &[vault_bump]]],
+        ),
+        paid,
+    )?;
+    
+    Ok(paid)
+}
+
+/// Leaf position of a card in its tree (Bubblegum's nonce doubles as the index)
+fn leaf_position(card: &MomentCard) -> Result<u32> {
+    u32::try_from(card.leaf_index).map_err(|_| error!(SocialRouletteError::InvalidMomentCard))
+}
@@ -4,4 +4,46 @@ pub mod onchain;
 
 pub use pyth::*;
 pub use switchboard::*;
-pub use onchain::*;
\ No newline at end of file
+pub use onchain::*;
+
+use anchor_lang::prelude::*;
+use crate::errors::SocialRouletteError;
+use crate::state::VerificationMethod;
+
+/// Determine the winning outcome from the market's oracle account.
+/// Shared by every market type that settles against an oracle.
+pub fn resolve_outcome(
+    verification_method: &VerificationMethod,
+    oracle_data: &AccountInfo,
+    target_value: i64,
+    num_outcomes: u8,
+    current_timestamp: i64,
+) -> Result<u8> {
+    let winning_outcome = match verification_method {
+        VerificationMethod::PythPrice => {
+            // Verify Pyth price feed
+            verify_pyth_price(target_value, oracle_data, current_timestamp)?
+        }
+        VerificationMethod::SwitchboardVRF => {
+            // Verify Switchboard VRF randomness
+            verify_switchboard_vrf(oracle_data, num_outcomes)?
+        }
+        VerificationMethod::OnChainData => {
+            // Verify on-chain data (block time, slot, etc.)
+            verify_onchain_data(oracle_data, target_value, num_outcomes)?
+        }
+        VerificationMethod::TwitterAPI => {
+            // For Twitter API and other off-chain data, admin must provide outcome manually
+            // This verification method requires the old flow
+            return Err(SocialRouletteError::RequiresOffChainOracle.into());
+        }
+    };
+
+    // Validate winning outcome is within range
+    require!(
+        winning_outcome < num_outcomes,
+        SocialRouletteError::InvalidOutcome
+    );
+
+    Ok(winning_outcome)
+}
@@ -1,12 +1,27 @@
 use anchor_lang::prelude::*;
 use crate::errors::SocialRouletteError;
-use crate::state::Round;
-
 pub fn verify_pyth_price(
-    round: &Round,
+    target_value: i64,
     pyth_price_account: &AccountInfo,
     current_timestamp: i64,
 ) -> Result<u8> {
+    let current_price_cents = read_pyth_price_cents(pyth_price_account, current_timestamp)?;
+    
+    // Determine winner
+    let winning_outcome = if current_price_cents >= target_value {
+        0
+    } else {
+        1
+    };
+    
+    Ok(winning_outcome)
+}
+
+/// Read a fresh Pyth price, converted to cents (e.g. 15000 = $150.00)
+pub fn read_pyth_price_cents(
+    pyth_price_account: &AccountInfo,
+    current_timestamp: i64,
+) -> Result<i64> {
     // Manual parsing of Pyth price account
     // Pyth V2 price account structure (simplified):
     // [0..8]: discriminator
@@ -43,12 +58,5 @@ pub fn verify_pyth_price(
         (price * 100) / (10_i64.pow(exponent.unsigned_abs()))
     };
     
-    // Determine winner
-    let winning_outcome = if current_price_cents >= round.target_value {
-        0
-    } else {
-        1
-    };
-    
-    Ok(winning_outcome)
+    Ok(current_price_cents)
 }
\ No newline at end of file
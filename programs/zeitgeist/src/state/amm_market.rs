@@ -0,0 +1,523 @@
+// Automated market maker (fixed-product) market state
+
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_OUTCOMES, SETTLEMENT_TIMEOUT};
+use crate::errors::SocialRouletteError;
+use crate::state::VerificationMethod;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum AmmStatus {
+    Trading,   // Shares can be bought and sold until trading_close_time
+    Settled,   // Outcome determined, winning shares redeemable
+    Cancelled, // No outcome; traders get back the collateral they paid in
+}
+
+/// Fixed-product market maker over `num_outcomes` outcome shares.
+///
+/// Every lamport of collateral backs one complete set (one share of each
+/// outcome). The pool's share reserves and the shares held by traders always
+/// add up to `collateral` for every outcome, so after settlement the pool's
+/// winning reserve is exactly what belongs to the creator.
+#[account]
+#[derive(InitSpace)]
+pub struct AmmMarket {
+    /// Unique market ID
+    pub market_id: u64,
+    
+    /// Liquidity provider; receives the pool's PnL after settlement
+    pub creator: Pubkey,
+    
+    #[max_len(200)]
+    pub question: String,
+    
+    /// Number of possible outcomes (2-10)
+    pub num_outcomes: u8,
+    
+    pub verification_method: VerificationMethod,
+    
+    /// Target value for comparison (e.g., price in cents: 15000 = $150.00)
+    pub target_value: i64,
+    
+    /// Data source address (e.g., Pyth price feed pubkey)
+    pub data_source: Pubkey,
+    
+    /// Authorized oracle that can settle this market
+    pub oracle: Pubkey,
+    
+    /// Trading closes at this time (Unix timestamp)
+    pub trading_close_time: i64,
+    
+    /// Market can be settled from this time (Unix timestamp)
+    pub end_time: i64,
+    
+    /// Outcome shares held by the pool
+    pub reserves: [u64; MAX_OUTCOMES as usize],
+    
+    /// Outcome shares held by traders
+    pub outstanding_shares: [u64; MAX_OUTCOMES as usize],
+    
+    /// Collateral backing all shares (lamports, excludes vault rent)
+    pub collateral: u64,
+    
+    /// Collateral traders have paid in and not yet taken back; refunded to
+    /// them if the market is cancelled
+    pub trader_collateral: u64,
+    
+    /// Winning outcome index (0-based, 255 = not set)
+    pub winning_outcome: u8,
+    
+    pub status: AmmStatus,
+    
+    /// Whether the creator has withdrawn the pool's winning reserve
+    pub liquidity_withdrawn: bool,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 56],
+}
+
+impl AmmMarket {
+    pub const VERSION: u8 = 1;
+    
+    /// Fixed-point scale used for price quotes
+    const PRICE_SCALE: u128 = 1_000_000_000_000_000_000;
+    
+    pub fn is_trading(&self, current_time: i64) -> bool {
+        self.status == AmmStatus::Trading && current_time < self.trading_close_time
+    }
+    
+    pub fn can_settle(&self, current_time: i64) -> bool {
+        self.status == AmmStatus::Trading && current_time >= self.end_time
+    }
+    
+    pub fn is_settled(&self) -> bool {
+        self.status == AmmStatus::Settled
+    }
+    
+    pub fn is_cancelled(&self) -> bool {
+        self.status == AmmStatus::Cancelled
+    }
+    
+    /// Whether the settlement window has passed without the market settling
+    pub fn settlement_expired(&self, current_time: i64) -> bool {
+        self.status == AmmStatus::Trading
+            && current_time >= self.end_time.saturating_add(SETTLEMENT_TIMEOUT)
+    }
+    
+    fn active_reserves(&self) -> &[u64] {
+        &self.reserves[..self.num_outcomes as usize]
+    }
+    
+    /// Price of each outcome in basis points; prices sum to ~10000
+    pub fn prices_bps(&self) -> Result<Vec<u64>> {
+        // An outcome's price is proportional to the inverse of its reserve
+        let inverses = self.active_reserves()
+            .iter()
+            .map(|&reserve| {
+                Self::PRICE_SCALE
+                    .checked_div(reserve as u128)
+                    .ok_or(error!(SocialRouletteError::ArithmeticOverflow))
+            })
+            .collect::<Result<Vec<u128>>>()?;
+        
+        let total = inverses
+            .iter()
+            .try_fold(0u128, |sum, &inverse| sum.checked_add(inverse))
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        
+        inverses
+            .iter()
+            .map(|&inverse| {
+                let price = inverse
+                    .checked_mul(10000)
+                    .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?
+                    .checked_div(total)
+                    .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+                Ok(price as u64)
+            })
+            .collect()
+    }
+    
+    /// Shares of `outcome` received for investing `amount` of collateral
+    pub fn calc_buy_shares(&self, outcome: u8, amount: u64) -> Result<u64> {
+        let reserves = self.active_reserves();
+        let target = *reserves
+            .get(outcome as usize)
+            .ok_or(error!(SocialRouletteError::InvalidOutcome))?;
+        
+        // New reserve of `outcome` keeping the product of reserves constant
+        // after `amount` complete sets are added; rounded up in the pool's favor
+        let mut new_target = target as u128;
+        for (index, &reserve) in reserves.iter().enumerate() {
+            if index == outcome as usize {
+                continue;
+            }
+            let grown = (reserve as u128)
+                .checked_add(amount as u128)
+                .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+            new_target = ceil_div(
+                new_target
+                    .checked_mul(reserve as u128)
+                    .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?,
+                grown,
+            )?;
+        }
+        
+        let shares = (target as u128)
+            .checked_add(amount as u128)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?
+            .checked_sub(new_target)
+            .ok_or(error!(SocialRouletteError::ArithmeticUnderflow))?;
+        
+        u64::try_from(shares).map_err(|_| error!(SocialRouletteError::ArithmeticOverflow))
+    }
+    
+    /// Shares of `outcome` the pool takes back to pay out `amount` of collateral
+    pub fn calc_sell_shares(&self, outcome: u8, amount: u64) -> Result<u64> {
+        let reserves = self.active_reserves();
+        let target = *reserves
+            .get(outcome as usize)
+            .ok_or(error!(SocialRouletteError::InvalidOutcome))?;
+        
+        // New reserve of `outcome` keeping the product of reserves constant
+        // after `amount` complete sets are burned; rounded up in the pool's favor
+        let mut new_target = target as u128;
+        for (index, &reserve) in reserves.iter().enumerate() {
+            if index == outcome as usize {
+                continue;
+            }
+            require!(reserve > amount, SocialRouletteError::InsufficientLiquidity);
+            let shrunk = (reserve - amount) as u128;
+            new_target = ceil_div(
+                new_target
+                    .checked_mul(reserve as u128)
+                    .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?,
+                shrunk,
+            )?;
+        }
+        
+        let shares = new_target
+            .checked_add(amount as u128)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?
+            .checked_sub(target as u128)
+            .ok_or(error!(SocialRouletteError::ArithmeticUnderflow))?;
+        
+        u64::try_from(shares).map_err(|_| error!(SocialRouletteError::ArithmeticOverflow))
+    }
+    
+    /// Apply a buy of `shares` of `outcome` for `amount` collateral
+    pub fn apply_buy(&mut self, outcome: u8, amount: u64, shares: u64) -> Result<()> {
+        let num_outcomes = self.num_outcomes as usize;
+        for reserve in self.reserves[..num_outcomes].iter_mut() {
+            *reserve = reserve
+                .checked_add(amount)
+                .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        }
+        
+        let index = outcome as usize;
+        self.reserves[index] = self.reserves[index]
+            .checked_sub(shares)
+            .ok_or(error!(SocialRouletteError::InsufficientLiquidity))?;
+        self.outstanding_shares[index] = self.outstanding_shares[index]
+            .checked_add(shares)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        self.collateral = self.collateral
+            .checked_add(amount)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        self.trader_collateral = self.trader_collateral
+            .checked_add(amount)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        Ok(())
+    }
+    
+    /// Apply a sell of `shares` of `outcome` for `amount` collateral
+    pub fn apply_sell(&mut self, outcome: u8, amount: u64, shares: u64) -> Result<()> {
+        let index = outcome as usize;
+        self.reserves[index] = self.reserves[index]
+            .checked_add(shares)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        self.outstanding_shares[index] = self.outstanding_shares[index]
+            .checked_sub(shares)
+            .ok_or(error!(SocialRouletteError::ArithmeticUnderflow))?;
+        
+        let num_outcomes = self.num_outcomes as usize;
+        for reserve in self.reserves[..num_outcomes].iter_mut() {
+            *reserve = reserve
+                .checked_sub(amount)
+                .ok_or(error!(SocialRouletteError::InsufficientLiquidity))?;
+        }
+        
+        self.collateral = self.collateral
+            .checked_sub(amount)
+            .ok_or(error!(SocialRouletteError::ArithmeticUnderflow))?;
+        Ok(())
+    }
+    
+    /// Record collateral a seller took back out of their cost basis
+    pub fn release_trader_collateral(&mut self, amount: u64) -> Result<()> {
+        self.trader_collateral = self.trader_collateral
+            .checked_sub(amount)
+            .ok_or(error!(SocialRouletteError::ArithmeticUnderflow))?;
+        Ok(())
+    }
+    
+    pub fn settle(&mut self, winning_outcome: u8) -> Result<()> {
+        require!(
+            self.status == AmmStatus::Trading,
+            SocialRouletteError::RoundAlreadySettled
+        );
+        
+        self.winning_outcome = winning_outcome;
+        self.status = AmmStatus::Settled;
+        Ok(())
+    }
+    
+    pub fn cancel(&mut self) -> Result<()> {
+        require!(
+            self.status == AmmStatus::Trading,
+            SocialRouletteError::RoundAlreadySettled
+        );
+        
+        self.status = AmmStatus::Cancelled;
+        Ok(())
+    }
+    
+    /// Refund a trader's position on a cancelled market, returning the amount:
+    /// the collateral they paid in and haven't already sold back
+    pub fn refund(&mut self, position: &AmmPosition) -> Result<u64> {
+        let amount = position.cost_basis.min(self.collateral);
+        
+        for (outstanding, &held) in self.outstanding_shares.iter_mut().zip(position.shares.iter()) {
+            *outstanding = outstanding
+                .checked_sub(held)
+                .ok_or(error!(SocialRouletteError::ArithmeticUnderflow))?;
+        }
+        self.trader_collateral = self.trader_collateral
+            .checked_sub(position.cost_basis)
+            .ok_or(error!(SocialRouletteError::ArithmeticUnderflow))?;
+        self.collateral -= amount;
+        Ok(amount)
+    }
+    
+    /// Pay out `shares` winning shares held by a trader
+    pub fn redeem(&mut self, shares: u64) -> Result<()> {
+        let index = self.winning_outcome as usize;
+        self.outstanding_shares[index] = self.outstanding_shares[index]
+            .checked_sub(shares)
+            .ok_or(error!(SocialRouletteError::ArithmeticUnderflow))?;
+        self.collateral = self.collateral
+            .checked_sub(shares)
+            .ok_or(error!(SocialRouletteError::ArithmeticUnderflow))?;
+        Ok(())
+    }
+    
+    /// Release the pool's share of the collateral to the creator, returning the
+    /// amount: its winning reserve, or whatever traders' refunds leave if the
+    /// market was cancelled
+    pub fn withdraw_liquidity(&mut self) -> Result<u64> {
+        require!(!self.liquidity_withdrawn, SocialRouletteError::AlreadyClaimed);
+        
+        let amount = match self.status {
+            AmmStatus::Cancelled => self.collateral.saturating_sub(self.trader_collateral),
+            _ => self.reserves[self.winning_outcome as usize],
+        };
+        self.collateral = self.collateral
+            .checked_sub(amount)
+            .ok_or(error!(SocialRouletteError::ArithmeticUnderflow))?;
+        self.reserves = [0; MAX_OUTCOMES as usize];
+        self.liquidity_withdrawn = true;
+        Ok(amount)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AmmPosition {
+    /// Market this position belongs to
+    pub market_id: u64,
+    
+    /// Trader holding the shares
+    pub user: Pubkey,
+    
+    /// Outcome shares held
+    pub shares: [u64; MAX_OUTCOMES as usize],
+    
+    /// Collateral paid in for these shares, less what sales took back
+    pub cost_basis: u64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 24],
+}
+
+impl AmmPosition {
+    pub const VERSION: u8 = 1;
+    
+    pub fn add_shares(&mut self, outcome: u8, shares: u64) -> Result<()> {
+        let held = self.shares
+            .get_mut(outcome as usize)
+            .ok_or(error!(SocialRouletteError::InvalidOutcome))?;
+        *held = held
+            .checked_add(shares)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        Ok(())
+    }
+    
+    pub fn add_cost(&mut self, amount: u64) -> Result<()> {
+        self.cost_basis = self.cost_basis
+            .checked_add(amount)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        Ok(())
+    }
+    
+    /// Take sale proceeds out of the cost basis, returning the part that was
+    /// cost; anything above it is profit
+    pub fn release_cost(&mut self, proceeds: u64) -> u64 {
+        let released = self.cost_basis.min(proceeds);
+        self.cost_basis -= released;
+        released
+    }
+    
+    pub fn remove_shares(&mut self, outcome: u8, shares: u64) -> Result<()> {
+        let held = self.shares
+            .get_mut(outcome as usize)
+            .ok_or(error!(SocialRouletteError::InvalidOutcome))?;
+        *held = held
+            .checked_sub(shares)
+            .ok_or(error!(SocialRouletteError::InsufficientShares))?;
+        Ok(())
+    }
+}
+
+fn ceil_div(numerator: u128, denominator: u128) -> Result<u128> {
+    require!(denominator > 0, SocialRouletteError::ArithmeticOverflow);
+    Ok(numerator.div_ceil(denominator))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn market(num_outcomes: u8, liquidity: u64) -> AmmMarket {
+        let mut reserves = [0; MAX_OUTCOMES as usize];
+        reserves[..num_outcomes as usize].fill(liquidity);
+        AmmMarket {
+            market_id: 1,
+            creator: Pubkey::new_unique(),
+            question: String::new(),
+            num_outcomes,
+            verification_method: VerificationMethod::PythPrice,
+            target_value: 0,
+            data_source: Pubkey::default(),
+            oracle: Pubkey::default(),
+            trading_close_time: 0,
+            end_time: 0,
+            reserves,
+            outstanding_shares: [0; MAX_OUTCOMES as usize],
+            collateral: liquidity,
+            trader_collateral: 0,
+            winning_outcome: 255,
+            status: AmmStatus::Trading,
+            liquidity_withdrawn: false,
+            bump: 255,
+            version: AmmMarket::VERSION,
+            reserved: [0; 56],
+        }
+    }
+
+    fn assert_backed(market: &AmmMarket) {
+        for index in 0..market.num_outcomes as usize {
+            assert_eq!(
+                market.reserves[index] + market.outstanding_shares[index],
+                market.collateral
+            );
+        }
+    }
+
+    #[test]
+    fn test_buy_moves_price_and_keeps_shares_backed() {
+        let mut market = market(2, 1_000_000);
+        assert_eq!(market.prices_bps().unwrap(), vec![5000, 5000]);
+
+        // Binary pool 1M/1M, buy 1M of outcome 0: reserve0 -> 1M*1M/2M = 500k
+        let shares = market.calc_buy_shares(0, 1_000_000).unwrap();
+        assert_eq!(shares, 1_500_000);
+        market.apply_buy(0, 1_000_000, shares).unwrap();
+        assert_backed(&market);
+
+        let prices = market.prices_bps().unwrap();
+        assert!(prices[0] > 5000 && prices[1] < 5000);
+    }
+
+    #[test]
+    fn test_sell_reverses_buy_without_profit() {
+        let mut market = market(3, 1_000_000);
+        let shares = market.calc_buy_shares(1, 250_000).unwrap();
+        market.apply_buy(1, 250_000, shares).unwrap();
+
+        // Selling back the full stake needs at least the shares bought
+        let shares_in = market.calc_sell_shares(1, 250_000).unwrap();
+        assert!(shares_in >= shares);
+
+        let sellable = market.calc_sell_shares(1, 200_000).unwrap();
+        market.apply_sell(1, 200_000, sellable).unwrap();
+        assert_backed(&market);
+    }
+
+    #[test]
+    fn test_settlement_pays_traders_then_creator() {
+        let mut market = market(2, 1_000_000);
+        let shares = market.calc_buy_shares(0, 500_000).unwrap();
+        market.apply_buy(0, 500_000, shares).unwrap();
+
+        market.settle(0).unwrap();
+        market.redeem(shares).unwrap();
+        let creator_payout = market.withdraw_liquidity().unwrap();
+
+        assert_eq!(market.collateral, 0);
+        assert_eq!(creator_payout + shares, 1_500_000);
+        assert!(market.withdraw_liquidity().is_err());
+    }
+
+    #[test]
+    fn test_cancellation_refunds_cost_basis() {
+        let mut market = market(2, 1_000_000);
+        let mut position = AmmPosition {
+            market_id: 1,
+            user: Pubkey::new_unique(),
+            shares: [0; MAX_OUTCOMES as usize],
+            cost_basis: 0,
+            bump: 255,
+            version: AmmPosition::VERSION,
+            reserved: [0; 24],
+        };
+
+        let shares = market.calc_buy_shares(0, 500_000).unwrap();
+        market.apply_buy(0, 500_000, shares).unwrap();
+        position.add_shares(0, shares).unwrap();
+        position.add_cost(500_000).unwrap();
+
+        let sold = market.calc_sell_shares(0, 100_000).unwrap();
+        market.apply_sell(0, 100_000, sold).unwrap();
+        position.remove_shares(0, sold).unwrap();
+        market.release_trader_collateral(position.release_cost(100_000)).unwrap();
+        market.cancel().unwrap();
+
+        // The trader gets back what they paid in net of sales; the LP keeps the rest
+        let creator_refund = market.withdraw_liquidity().unwrap();
+        let trader_refund = market.refund(&position).unwrap();
+        assert_eq!(trader_refund, 400_000);
+        assert_eq!(creator_refund, 1_000_000);
+        assert_eq!(market.collateral, 0);
+    }
+}
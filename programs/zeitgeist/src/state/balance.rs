@@ -0,0 +1,52 @@
+// Custodial betting balance state
+
+use anchor_lang::prelude::*;
+use crate::errors::SocialRouletteError;
+
+/// Internal lamport balance a user can bet from and be paid into without a
+/// wallet transfer each time. The lamports are held by this account itself,
+/// on top of its rent-exempt minimum.
+#[account]
+#[derive(InitSpace)]
+pub struct Balance {
+    /// Owner of the balance
+    pub user: Pubkey,
+    
+    /// Lamports available to bet or withdraw
+    pub amount: u64,
+    
+    /// Total deposited from the wallet (lamports)
+    pub total_deposited: u64,
+    
+    /// Total withdrawn to the wallet (lamports)
+    pub total_withdrawn: u64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl Balance {
+    pub const VERSION: u8 = 1;
+    
+    /// Add lamports received from a deposit, winnings or a refund
+    pub fn credit(&mut self, amount: u64) -> Result<()> {
+        self.amount = self.amount
+            .checked_add(amount)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        Ok(())
+    }
+    
+    /// Remove lamports for a bet or withdrawal
+    pub fn debit(&mut self, amount: u64) -> Result<()> {
+        self.amount = self.amount
+            .checked_sub(amount)
+            .ok_or(error!(SocialRouletteError::InsufficientBalance))?;
+        Ok(())
+    }
+}
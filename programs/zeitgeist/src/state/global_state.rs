@@ -1,6 +1,7 @@
 // Global program state
 
 use anchor_lang::prelude::*;
+use crate::constants::MAX_MOMENT_URI_BASE_LEN;
 
 #[account]
 #[derive(InitSpace)]
@@ -21,16 +22,39 @@ pub struct GlobalState {
     /// Total volume wagered across all rounds (in lamports)
     pub total_volume: u64,
     
-    /// Whether the program is paused
-    pub paused: bool,
+    /// Paused instruction families (bitmask of `PAUSE_*` flags)
+    pub pause_flags: u8,
     
     /// Bump seed for PDA derivation
     pub bump: u8,
 
     pub platform_wallet: Pubkey,  
+
+    /// Account layout version (see `migrate`)
+    pub version: u8,
+
+    /// Fee kept in the pool when a prediction is withdrawn early (basis points)
+    pub exit_fee_bps: u16,
+
+    /// Base URI for moment card metadata; each card's URI is `{base}/{moment card PDA}`
+    #[max_len(MAX_MOMENT_URI_BASE_LEN)]
+    pub moment_uri_base: String,
+
+    /// Rounds created through create_round_auto; the next auto round id is
+    /// `Round::auto_round_id(auto_rounds)`
+    pub auto_rounds: u64,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 34],
 }
 
 impl GlobalState {
+    pub const VERSION: u8 = 4;
+    
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.pause_flags & flags != 0
+    }
+    
     pub fn increment_rounds(&mut self) -> Result<()> {
         self.total_rounds = self.total_rounds
             .checked_add(1)
@@ -38,6 +62,17 @@ impl GlobalState {
         Ok(())
     }
     
+    /// Take the next auto round id and advance the auto round counter
+    pub fn next_auto_round_id(&mut self) -> Result<u64> {
+        let round_id = crate::state::Round::auto_round_id(self.auto_rounds);
+        require!(
+            crate::state::Round::is_auto_round_id(round_id),
+            crate::errors::SocialRouletteError::ArithmeticOverflow
+        );
+        self.auto_rounds += 1;
+        Ok(round_id)
+    }
+    
     pub fn increment_tournaments(&mut self) -> Result<()> {
         self.total_tournaments = self.total_tournaments
             .checked_add(1)
@@ -52,3 +87,36 @@ impl GlobalState {
         Ok(())
     }
 }
+
+impl crate::state::Migrate for GlobalState {
+    const LATEST_VERSION: u8 = GlobalState::VERSION;
+    const SPACE: usize = 8 + GlobalState::INIT_SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        if self.version < 1 {
+            // v1: adds version and reserved
+            self.reserved = [0; 34];
+        }
+
+        if self.version < 2 {
+            // v2: adds exit_fee_bps
+            self.exit_fee_bps = crate::constants::DEFAULT_EXIT_FEE_BPS;
+        }
+
+        if self.version < 3 {
+            // v3: adds moment_uri_base, carved out of reserved
+            self.moment_uri_base = crate::constants::DEFAULT_MOMENT_URI_BASE.to_string();
+        }
+
+        if self.version < 4 {
+            // v4: adds auto_rounds, carved out of reserved
+            self.auto_rounds = 0;
+        }
+
+        self.version = Self::VERSION;
+    }
+}
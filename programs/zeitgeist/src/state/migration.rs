@@ -0,0 +1,123 @@
+// In-place account layout migration
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::errors::SocialRouletteError;
+
+/// Account types whose on-chain layout can be upgraded in place.
+///
+/// New fields are carved out of each account's `reserved` bytes (or appended
+/// after them), so an old account zero-filled up to `SPACE` deserializes with
+/// every new field at its zero default. `upgrade` then fills in anything that
+/// needs a non-zero default and stamps the latest version.
+pub trait Migrate: AccountSerialize + AccountDeserialize {
+    /// Latest layout version written by this program build
+    const LATEST_VERSION: u8;
+
+    /// Account size for the latest layout (including discriminator)
+    const SPACE: usize;
+
+    fn version(&self) -> u8;
+
+    /// Whether the account's current state can be upgraded safely
+    fn can_upgrade(&self) -> bool {
+        true
+    }
+
+    fn upgrade(&mut self);
+}
+
+/// Realloc `account` to the latest layout of `T`, topping up rent from `payer`,
+/// then upgrade and rewrite its data. Returns the version migrated from and
+/// the upgraded account.
+pub fn migrate_account<'info, T: Migrate>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<(u8, T)> {
+    require_keys_eq!(
+        *account.owner,
+        crate::ID,
+        anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+    );
+
+    if account.data_len() < T::SPACE {
+        let rent = Rent::get()?;
+        let top_up = rent
+            .minimum_balance(T::SPACE)
+            .saturating_sub(account.lamports());
+
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    system_program::Transfer {
+                        from: payer.clone(),
+                        to: account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+
+        account.realloc(T::SPACE, true)?;
+    }
+
+    let mut data = T::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    let from_version = data.version();
+
+    require!(
+        from_version < T::LATEST_VERSION,
+        SocialRouletteError::AlreadyMigrated
+    );
+    require!(data.can_upgrade(), SocialRouletteError::MigrationNotAllowed);
+
+    data.upgrade();
+    data.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    Ok((from_version, data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::GlobalState;
+
+    #[test]
+    fn test_legacy_layout_upgrades_after_zero_fill() {
+        let current = GlobalState {
+            admin: Pubkey::new_unique(),
+            platform_fee_bps: 200,
+            total_rounds: 7,
+            total_tournaments: 1,
+            total_volume: 42,
+            pause_flags: 0,
+            bump: 254,
+            platform_wallet: Pubkey::new_unique(),
+            version: GlobalState::VERSION,
+            exit_fee_bps: 0,
+            moment_uri_base: String::new(),
+            auto_rounds: 0,
+            reserved: [0; 34],
+        };
+
+        // A v0 account is the same bytes without the trailing version and later fields
+        let mut data = Vec::new();
+        current.try_serialize(&mut data).unwrap();
+        data.truncate(data.len() - 1 - 2 - 4 - 8 - 34);
+
+        // Realloc zero-fills the new tail
+        data.resize(GlobalState::SPACE, 0);
+
+        let mut legacy = GlobalState::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(legacy.version(), 0);
+        assert_eq!(legacy.admin, current.admin);
+        assert_eq!(legacy.total_rounds, 7);
+
+        legacy.upgrade();
+        assert_eq!(legacy.version(), GlobalState::LATEST_VERSION);
+        assert_eq!(legacy.exit_fee_bps, crate::constants::DEFAULT_EXIT_FEE_BPS);
+        assert_eq!(legacy.moment_uri_base, crate::constants::DEFAULT_MOMENT_URI_BASE);
+        assert_eq!(legacy.auto_rounds, 0);
+    }
+}
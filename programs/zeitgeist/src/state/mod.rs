@@ -5,9 +5,33 @@ pub mod round;
 pub mod prediction;
 pub mod user_stats;
 pub mod tournament;
+pub mod roles;
+pub mod migration;
+pub mod round_series;
+pub mod round_lookup;
+pub mod amm_market;
+pub mod parlay;
+pub mod session_token;
+pub mod balance;
+pub mod season;
+pub mod moment_card;
+pub mod moment_config;
+pub mod moment_upgrades;
 
 pub use global_state::*;
 pub use round::*;
 pub use prediction::*;
 pub use user_stats::*;
 pub use tournament::*;
+pub use roles::*;
+pub use migration::*;
+pub use round_series::*;
+pub use round_lookup::*;
+pub use amm_market::*;
+pub use parlay::*;
+pub use session_token::*;
+pub use balance::*;
+pub use season::*;
+pub use moment_card::*;
+pub use moment_config::*;
+pub use moment_upgrades::*;
@@ -0,0 +1,183 @@
+// Moment card state
+
+use anchor_lang::prelude::*;
+use crate::constants::RARITY_TIER_COUNT;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum MomentRarity {
+    Common,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+impl MomentRarity {
+    /// Every tier, least to most rare
+    pub const ALL: [MomentRarity; RARITY_TIER_COUNT] = [
+        MomentRarity::Common,
+        MomentRarity::Rare,
+        MomentRarity::Epic,
+        MomentRarity::Legendary,
+    ];
+    
+    pub fn name(&self) -> &'static str {
+        match self {
+            MomentRarity::Common => "Common",
+            MomentRarity::Rare => "Rare",
+            MomentRarity::Epic => "Epic",
+            MomentRarity::Legendary => "Legendary",
+        }
+    }
+    
+    /// This rarity raised by `tiers`, capped at Legendary
+    pub fn upgraded(self, tiers: u8) -> Self {
+        let index = (self as usize).saturating_add(tiers as usize);
+        Self::ALL[index.min(RARITY_TIER_COUNT - 1)]
+    }
+}
+
+/// On-chain attributes of a minted moment card, one per prediction. The cNFT's
+/// URI points at this account, so the metadata server only renders what's
+/// recorded here; `asset_id` links back to the cNFT itself.
+#[account]
+#[derive(InitSpace)]
+pub struct MomentCard {
+    /// Round the card commemorates
+    pub round_id: u64,
+    
+    /// End time of the commemorated round (Unix timestamp); orders cards by
+    /// when their rounds were played
+    pub round_end_time: i64,
+    
+    /// Prediction the card was minted from
+    pub prediction: Pubkey,
+    
+    /// Owner of the prediction at mint time
+    pub user: Pubkey,
+    
+    /// Round question at mint time
+    #[max_len(200)]
+    pub question: String,
+    
+    /// First outcome the user picked
+    pub picked_outcome: u8,
+    
+    /// Outcome the round settled on
+    pub winning_outcome: u8,
+    
+    /// Total stake on the prediction (lamports)
+    pub stake: u64,
+    
+    /// Payout / stake in basis points (0 for a losing prediction)
+    pub payout_multiplier_bps: u64,
+    
+    pub rarity: MomentRarity,
+    
+    /// Mint time (Unix timestamp)
+    pub minted_at: i64,
+    
+    /// Compressed asset id, derived from `merkle_tree` and `leaf_index`
+    pub asset_id: Pubkey,
+    
+    /// Bubblegum tree the card was minted into
+    pub merkle_tree: Pubkey,
+    
+    /// Leaf index (Bubblegum nonce) of the card in `merkle_tree`
+    pub leaf_index: u64,
+    
+    /// Leaf metadata hash, needed to prove or burn the card
+    pub data_hash: [u8; 32],
+    
+    /// Leaf creators hash, needed to prove or burn the card
+    pub creator_hash: [u8; 32],
+    
+    /// Burned to forge another card; the cNFT no longer exists
+    pub burned: bool,
+    
+    /// Tiers gained by `evolve_moment_card`
+    pub evolutions: u8,
+    
+    /// Already presented as a win to evolve another card
+    pub win_proof_spent: bool,
+    
+    /// Day number (Unix time / 86400) the holder perk was last used; one use per day
+    pub last_perk_day: u32,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl MomentCard {
+    pub const VERSION: u8 = 1;
+    
+    /// Point the record at a newly minted cNFT
+    pub fn set_leaf(&mut self, merkle_tree: Pubkey, minted: &crate::moment::MintedCard) {
+        self.asset_id = minted.asset_id;
+        self.merkle_tree = merkle_tree;
+        self.leaf_index = minted.leaf_index;
+        self.data_hash = minted.data_hash;
+        self.creator_hash = minted.creator_hash;
+        self.burned = false;
+    }
+    
+    /// Use the card's holder perk, at most once per day
+    pub fn use_perk(&mut self, current_time: i64) -> Result<()> {
+        let day = (current_time / crate::constants::SECONDS_PER_DAY) as u32;
+        require!(
+            self.last_perk_day < day,
+            crate::errors::SocialRouletteError::PerkAlreadyUsed
+        );
+        self.last_perk_day = day;
+        Ok(())
+    }
+    
+    /// Whether this card can be presented as a later win when evolving `card`.
+    /// Cards are ordered by their rounds, not by mint time, which the holder
+    /// controls; holding the card is proven separately against its leaf.
+    pub fn is_win_proof_for(&self, card: &MomentCard) -> bool {
+        !self.burned
+            && self.payout_multiplier_bps > 0
+            && !self.win_proof_spent
+            && self.prediction != card.prediction
+            && self.round_end_time > card.round_end_time
+    }
+    
+    /// Realized payout multiplier in basis points (10000 = 1x)
+    pub fn payout_multiplier_bps(stake: u64, payout: u64) -> Result<u64> {
+        if stake == 0 {
+            return Ok(0);
+        }
+        
+        let multiplier = (payout as u128)
+            .checked_mul(10000)
+            .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?
+            / stake as u128;
+        
+        u64::try_from(multiplier).map_err(|_| error!(crate::errors::SocialRouletteError::ArithmeticOverflow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rarity_upgrade_caps_at_legendary() {
+        assert_eq!(MomentRarity::Common.upgraded(0), MomentRarity::Common);
+        assert_eq!(MomentRarity::Rare.upgraded(1), MomentRarity::Epic);
+        assert_eq!(MomentRarity::Epic.upgraded(3), MomentRarity::Legendary);
+    }
+
+    #[test]
+    fn test_payout_multiplier_bps() {
+        assert_eq!(MomentCard::payout_multiplier_bps(1_000, 2_500).unwrap(), 25_000);
+        assert_eq!(MomentCard::payout_multiplier_bps(1_000, 0).unwrap(), 0);
+        assert_eq!(MomentCard::payout_multiplier_bps(0, 100).unwrap(), 0);
+    }
+}
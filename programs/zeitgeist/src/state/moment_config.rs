@@ -0,0 +1,166 @@
+// Moment card configuration state
+
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::state::{MomentRarity, Prediction, Round};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
+pub struct RarityTier {
+    /// Most likely winning outcome (implied odds, in basis points) that still earns this tier
+    pub max_odds_bps: u16,
+    
+    /// Display name rendered by the metadata server
+    #[max_len(MAX_RARITY_NAME_LEN)]
+    pub name: String,
+}
+
+/// Where moment cards are minted and how their rarity is scored. The program's
+/// moment authority PDA is both the tree creator and the collection's update
+/// authority, so only `mint_moment_card` can mint, and every card is verified
+/// in the collection.
+#[account]
+#[derive(InitSpace)]
+pub struct MomentConfig {
+    /// Bubblegum tree cards are minted into
+    pub merkle_tree: Pubkey,
+    
+    /// Official moment card collection (its update authority is the moment authority PDA)
+    pub collection_mint: Pubkey,
+    
+    /// Bump seed of the moment authority PDA
+    pub authority_bump: u8,
+    
+    /// Rarity tiers indexed by `MomentRarity`. A winning card gets the rarest tier
+    /// whose `max_odds_bps` covers its odds; losing cards are Common.
+    pub rarity_tiers: [RarityTier; RARITY_TIER_COUNT],
+    
+    /// Early bettor modifier: positions opened within this many seconds of the
+    /// round start are bumped one tier (0 = off)
+    pub early_bettor_window: i64,
+    
+    /// Comeback modifier: a win on a position opened after at least this many
+    /// straight losses is bumped one tier (0 = off)
+    pub comeback_loss_streak: u32,
+    
+    /// Largest stake modifier: the round's largest position is bumped one tier
+    pub largest_stake_bonus: bool,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 64],
+}
+
+impl MomentConfig {
+    pub const VERSION: u8 = 1;
+    
+    /// The thresholds `mint_moment_card` originally hardcoded
+    pub fn default_rarity_tiers() -> [RarityTier; RARITY_TIER_COUNT] {
+        let thresholds = [10000, 10000, DEFAULT_EPIC_ODDS_BPS, DEFAULT_LEGENDARY_ODDS_BPS];
+        
+        std::array::from_fn(|i| RarityTier {
+            max_odds_bps: thresholds[i],
+            name: MomentRarity::ALL[i].name().to_string(),
+        })
+    }
+    
+    /// Tiers must be named and get no easier as rarity rises
+    pub fn validate_rarity_tiers(tiers: &[RarityTier; RARITY_TIER_COUNT]) -> Result<()> {
+        let named = tiers
+            .iter()
+            .all(|tier| !tier.name.is_empty() && tier.name.len() <= MAX_RARITY_NAME_LEN);
+        let ordered = tiers
+            .windows(2)
+            .all(|pair| pair[0].max_odds_bps >= pair[1].max_odds_bps);
+        
+        require!(
+            named && ordered && tiers[0].max_odds_bps <= 10000,
+            crate::errors::SocialRouletteError::InvalidRarityTiers
+        );
+        Ok(())
+    }
+    
+    /// Tier for a card before modifiers, from the winning outcome's implied odds
+    pub fn base_rarity(&self, won: bool, odds_bps: u64) -> MomentRarity {
+        if !won {
+            return MomentRarity::Common;
+        }
+        
+        MomentRarity::ALL
+            .into_iter()
+            .rev()
+            .find(|rarity| odds_bps <= self.rarity_tiers[*rarity as usize].max_odds_bps as u64)
+            .unwrap_or(MomentRarity::Common)
+    }
+    
+    /// Number of tiers a winning card is bumped by its modifiers
+    pub fn rarity_modifiers(&self, round: &Round, prediction: &Prediction) -> u8 {
+        let early = self.early_bettor_window > 0
+            && prediction.timestamp.saturating_sub(round.start_time) <= self.early_bettor_window;
+        let largest = self.largest_stake_bonus
+            && round.largest_stake > 0
+            && prediction.amount >= round.largest_stake;
+        let comeback = self.comeback_loss_streak > 0
+            && prediction.loss_streak_at_entry >= self.comeback_loss_streak;
+        
+        early as u8 + largest as u8 + comeback as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> MomentConfig {
+        MomentConfig {
+            merkle_tree: Pubkey::default(),
+            collection_mint: Pubkey::default(),
+            authority_bump: 0,
+            rarity_tiers: MomentConfig::default_rarity_tiers(),
+            early_bettor_window: 0,
+            comeback_loss_streak: 0,
+            largest_stake_bonus: false,
+            bump: 0,
+            version: MomentConfig::VERSION,
+            reserved: [0; 64],
+        }
+    }
+
+    #[test]
+    fn test_default_tiers_match_legacy_thresholds() {
+        let c = config();
+        assert_eq!(c.base_rarity(false, 500), MomentRarity::Common);
+        assert_eq!(c.base_rarity(true, 1500), MomentRarity::Legendary);
+        assert_eq!(c.base_rarity(true, 1999), MomentRarity::Legendary);
+        assert_eq!(c.base_rarity(true, 2000), MomentRarity::Epic);
+        assert_eq!(c.base_rarity(true, 3000), MomentRarity::Epic);
+        assert_eq!(c.base_rarity(true, 3999), MomentRarity::Epic);
+        assert_eq!(c.base_rarity(true, 4000), MomentRarity::Rare);
+        assert_eq!(c.base_rarity(true, 9000), MomentRarity::Rare);
+        assert_eq!(c.base_rarity(true, 10000), MomentRarity::Rare);
+    }
+
+    #[test]
+    fn test_winner_can_be_common_when_rare_is_tightened() {
+        let mut c = config();
+        c.rarity_tiers[MomentRarity::Rare as usize].max_odds_bps = 6000;
+        assert_eq!(c.base_rarity(true, 8000), MomentRarity::Common);
+    }
+
+    #[test]
+    fn test_validate_rarity_tiers() {
+        let mut tiers = MomentConfig::default_rarity_tiers();
+        assert!(MomentConfig::validate_rarity_tiers(&tiers).is_ok());
+
+        tiers[3].max_odds_bps = 5000; // Legendary easier than Epic
+        assert!(MomentConfig::validate_rarity_tiers(&tiers).is_err());
+
+        let mut tiers = MomentConfig::default_rarity_tiers();
+        tiers[1].name = String::new();
+        assert!(MomentConfig::validate_rarity_tiers(&tiers).is_err());
+    }
+}
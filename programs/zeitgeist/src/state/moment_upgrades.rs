@@ -0,0 +1,60 @@
+// Moment card upgrade history state
+
+use anchor_lang::prelude::*;
+
+/// A holder's record of combining and evolving moment cards
+#[account]
+#[derive(InitSpace)]
+pub struct MomentUpgrades {
+    pub user: Pubkey,
+    
+    /// Cards forged by burning lower-tier cards
+    pub combines: u32,
+    
+    /// Cards burned as combine inputs
+    pub cards_burned: u32,
+    
+    /// Tiers gained by evolving cards
+    pub evolutions: u32,
+    
+    /// Last combine or evolution (Unix timestamp)
+    pub last_upgrade_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl MomentUpgrades {
+    pub const VERSION: u8 = 1;
+    
+    /// Initialize on first use (init_if_needed handles account creation)
+    pub fn init_if_new(&mut self, user: Pubkey, bump: u8) {
+        if self.version == 0 {
+            self.user = user;
+            self.combines = 0;
+            self.cards_burned = 0;
+            self.evolutions = 0;
+            self.last_upgrade_at = 0;
+            self.bump = bump;
+            self.version = Self::VERSION;
+            self.reserved = [0; 32];
+        }
+    }
+    
+    pub fn record_combine(&mut self, cards_burned: u32, current_time: i64) {
+        self.combines = self.combines.saturating_add(1);
+        self.cards_burned = self.cards_burned.saturating_add(cards_burned);
+        self.last_upgrade_at = current_time;
+    }
+    
+    pub fn record_evolution(&mut self, current_time: i64) {
+        self.evolutions = self.evolutions.saturating_add(1);
+        self.last_upgrade_at = current_time;
+    }
+}
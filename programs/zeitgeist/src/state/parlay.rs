@@ -0,0 +1,205 @@
+// Parlay (multi-round combined prediction) state
+
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_PARLAY_LEGS, MAX_PARLAY_MULTIPLIER_BPS};
+use crate::errors::SocialRouletteError;
+
+/// How a leg's round ended, from the parlay's point of view
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LegResult {
+    Won(u64), // Carries the round's closing multiplier (10000 = 1x)
+    Lost,
+    Void, // Round was cancelled; the leg is dropped from the parlay
+}
+
+/// A leg as requested by the user when placing a parlay
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ParlayPick {
+    pub round_id: u64,
+    pub outcome: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ParlayLeg {
+    /// Round this leg picks in
+    pub round_id: u64,
+
+    /// Picked outcome (0-based index)
+    pub outcome: u8,
+
+    /// Parimutuel multiplier quoted when the parlay was placed (10000 = 1x).
+    /// The leg pays the lower of this and the round's closing multiplier.
+    pub multiplier_bps: u64,
+}
+
+/// A house-backed parlay: the stake wins `stake * product(leg multipliers)`
+/// from the parlay pool if every leg wins. Each leg pays the lower of its quoted
+/// and closing odds, so moving a pool before betting closes can't inflate it. Legs on cancelled rounds are void
+/// and drop out of the product; if every leg is void the stake is refunded.
+#[account]
+#[derive(InitSpace)]
+pub struct Parlay {
+    /// Client-chosen id, unique per user
+    pub parlay_id: u64,
+
+    /// User who placed the parlay
+    pub user: Pubkey,
+
+    #[max_len(MAX_PARLAY_LEGS)]
+    pub legs: Vec<ParlayLeg>,
+
+    /// Stake escrowed in the parlay pool (lamports)
+    pub amount: u64,
+
+    /// Payout if every leg wins at its quoted odds; reserved against the pool until settlement
+    pub potential_payout: u64,
+
+    /// Timestamp when the parlay was placed
+    pub timestamp: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl Parlay {
+    pub const VERSION: u8 = 1;
+
+    /// Combine leg multipliers into one, capped at `MAX_PARLAY_MULTIPLIER_BPS`
+    pub fn combined_multiplier_bps<'a>(multipliers: impl IntoIterator<Item = &'a u64>) -> Result<u64> {
+        let mut combined: u128 = 10000;
+        for multiplier in multipliers {
+            combined = combined
+                .checked_mul(*multiplier as u128)
+                .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?
+                / 10000;
+            combined = combined.min(MAX_PARLAY_MULTIPLIER_BPS as u128);
+        }
+
+        Ok(combined as u64)
+    }
+
+    /// Apply a multiplier to a stake
+    pub fn payout_for(amount: u64, multiplier_bps: u64) -> Result<u64> {
+        let payout = (amount as u128)
+            .checked_mul(multiplier_bps as u128)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?
+            / 10000;
+
+        u64::try_from(payout).map_err(|_| error!(SocialRouletteError::ArithmeticOverflow))
+    }
+
+    /// Amount paid to the user given each leg's result, in leg order
+    pub fn settled_payout(&self, results: &[LegResult]) -> Result<u64> {
+        require!(
+            results.len() == self.legs.len(),
+            SocialRouletteError::InvalidParlay
+        );
+
+        if results.contains(&LegResult::Lost) {
+            return Ok(0);
+        }
+
+        // A winning leg pays the lower of its quoted and closing odds, floored at 1x
+        // so dropping void legs never raises the payout
+        let multipliers: Vec<u64> = self.legs
+            .iter()
+            .zip(results)
+            .filter_map(|(leg, result)| match result {
+                LegResult::Won(closing_bps) => Some(leg.multiplier_bps.min(*closing_bps).max(10000)),
+                _ => None,
+            })
+            .collect();
+        let multiplier = Self::combined_multiplier_bps(&multipliers)?;
+
+        Ok(Self::payout_for(self.amount, multiplier)?.min(self.potential_payout))
+    }
+}
+
+/// House bankroll backing parlay payouts. Lamports live in the parlay vault PDA.
+#[account]
+#[derive(InitSpace)]
+pub struct ParlayPool {
+    /// Sum of `potential_payout` over all unsettled parlays
+    pub total_liability: u64,
+
+    /// Total stakes placed into the pool (lamports)
+    pub total_staked: u64,
+
+    /// Total paid out to parlay winners (lamports)
+    pub total_paid: u64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl ParlayPool {
+    pub const VERSION: u8 = 1;
+
+    /// Reserve a new parlay's potential payout, given the vault balance available
+    /// for payouts (excluding rent) after its stake was deposited
+    pub fn reserve(&mut self, stake: u64, potential_payout: u64, available: u64) -> Result<()> {
+        let liability = self.total_liability
+            .checked_add(potential_payout)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        require!(
+            liability <= available,
+            SocialRouletteError::InsufficientParlayLiquidity
+        );
+
+        self.total_liability = liability;
+        self.total_staked = self.total_staked
+            .checked_add(stake)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        Ok(())
+    }
+
+    /// Release a settled parlay's reservation and record what it paid out
+    pub fn release(&mut self, potential_payout: u64, paid: u64) -> Result<()> {
+        self.total_liability = self.total_liability
+            .checked_sub(potential_payout)
+            .ok_or(error!(SocialRouletteError::ArithmeticUnderflow))?;
+        self.total_paid = self.total_paid
+            .checked_add(paid)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combined_multiplier() {
+        // 2x * 1.5x = 3x
+        assert_eq!(Parlay::combined_multiplier_bps(&[20000, 15000]).unwrap(), 30000);
+        // No legs = 1x
+        assert_eq!(Parlay::combined_multiplier_bps(&[]).unwrap(), 10000);
+        // Capped
+        assert_eq!(
+            Parlay::combined_multiplier_bps(&[1_000_000, 1_000_000]).unwrap(),
+            MAX_PARLAY_MULTIPLIER_BPS
+        );
+    }
+
+    #[test]
+    fn test_payout_for() {
+        // 1000 at 3x = 3000
+        assert_eq!(Parlay::payout_for(1000, 30000).unwrap(), 3000);
+        // Rounds down
+        assert_eq!(Parlay::payout_for(3, 15000).unwrap(), 4);
+        assert!(Parlay::payout_for(u64::MAX, 20000).is_err());
+    }
+}
@@ -1,6 +1,8 @@
 // User prediction state
 
 use anchor_lang::prelude::*;
+use crate::constants::MAX_OUTCOMES;
+use crate::errors::SocialRouletteError;
 
 #[account]
 #[derive(InitSpace)]
@@ -11,13 +13,13 @@ pub struct Prediction {
     /// User who made the prediction
     pub user: Pubkey,
     
-    /// Amount wagered (lamports)
+    /// Total amount wagered across all outcomes (lamports)
     pub amount: u64,
     
-    /// Predicted outcome (0-based index)
+    /// First predicted outcome (0-based index); see `outcome_amounts` for all stakes
     pub outcome: u8,
     
-    /// Timestamp when prediction was placed
+    /// Timestamp when the position was opened
     pub timestamp: i64,
     
     /// Whether winnings have been claimed
@@ -25,11 +27,78 @@ pub struct Prediction {
     
     /// Bump seed for PDA derivation
     pub bump: u8,
+
+    /// Account layout version (see `migrate`)
+    pub version: u8,
+
+    /// Amount wagered on each outcome (lamports)
+    pub outcome_amounts: [u64; MAX_OUTCOMES as usize],
+
+    /// Moment card perk: share of this position's platform fee rebated on a win (basis points)
+    pub perk_fee_discount_bps: u16,
+
+    /// Moment card perk: bonus on winnings paid by the perk vault (basis points)
+    pub perk_boost_bps: u16,
+
+    /// User's loss streak when the position was opened (read by the comeback rarity modifier)
+    pub loss_streak_at_entry: u32,
+
+    /// Moment card perk: stake placed with the card; the perk only covers this part of the position
+    pub perk_amount: u64,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 16],
 }
 
 impl Prediction {
+    pub const VERSION: u8 = 5;
+    
     pub fn is_winner(&self, winning_outcome: u8) -> bool {
-        self.outcome == winning_outcome
+        self.stake_on(winning_outcome) > 0
+    }
+    
+    /// Amount staked on `outcome` (0 for out-of-range outcomes)
+    pub fn stake_on(&self, outcome: u8) -> u64 {
+        self.outcome_amounts
+            .get(outcome as usize)
+            .copied()
+            .unwrap_or(0)
+    }
+    
+    /// Reduce the position on `outcome`, returning the remaining stake on that outcome
+    pub fn remove_stake(&mut self, outcome: u8, amount: u64) -> Result<u64> {
+        let stake = self.outcome_amounts
+            .get_mut(outcome as usize)
+            .ok_or(error!(SocialRouletteError::InvalidOutcome))?;
+        
+        *stake = stake
+            .checked_sub(amount)
+            .ok_or(error!(SocialRouletteError::InvalidPredictionAmount))?;
+        let remaining = *stake;
+        
+        self.amount = self.amount
+            .checked_sub(amount)
+            .ok_or(error!(SocialRouletteError::ArithmeticUnderflow))?;
+        
+        Ok(remaining)
+    }
+    
+    /// Add to the position on `outcome`, returning the new stake on that outcome
+    pub fn add_stake(&mut self, outcome: u8, amount: u64) -> Result<u64> {
+        let stake = self.outcome_amounts
+            .get_mut(outcome as usize)
+            .ok_or(error!(SocialRouletteError::InvalidOutcome))?;
+        
+        *stake = stake
+            .checked_add(amount)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        let new_stake = *stake;
+        
+        self.amount = self.amount
+            .checked_add(amount)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        
+        Ok(new_stake)
     }
     
     pub fn can_claim(&self, round_settled: bool) -> bool {
@@ -40,4 +109,134 @@ impl Prediction {
         self.claimed = true;
         Ok(())
     }
+    
+    /// Apply a moment card perk to `amount`, the stake placed with the card.
+    /// A position carries one perk, so later top-ups can't widen it.
+    pub fn apply_perk(&mut self, fee_discount_bps: u16, boost_bps: u16, amount: u64) -> Result<()> {
+        require!(self.perk_amount == 0, SocialRouletteError::PerkAlreadyApplied);
+        
+        self.perk_fee_discount_bps = fee_discount_bps;
+        self.perk_boost_bps = boost_bps;
+        self.perk_amount = amount;
+        Ok(())
+    }
+    
+    /// Part of the position's `winnings` earned by the stake the perk covers
+    pub fn perk_winnings(&self, winnings: u64) -> u64 {
+        if self.amount == 0 {
+            return 0;
+        }
+        
+        let covered = self.perk_amount.min(self.amount);
+        (winnings as u128 * covered as u128 / self.amount as u128) as u64
+    }
+}
+
+impl crate::state::Migrate for Prediction {
+    const LATEST_VERSION: u8 = Prediction::VERSION;
+    const SPACE: usize = 8 + Prediction::INIT_SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        if self.version < 1 {
+            // v1: adds version and reserved
+            self.reserved = [0; 16];
+        }
+
+        if self.version < 2 {
+            // v2: adds outcome_amounts; older positions hold a single outcome
+            self.outcome_amounts = [0; MAX_OUTCOMES as usize];
+            if let Some(stake) = self.outcome_amounts.get_mut(self.outcome as usize) {
+                *stake = self.amount;
+            }
+        }
+
+        if self.version < 3 {
+            // v3: adds moment card perks, carved out of reserved
+            self.perk_fee_discount_bps = 0;
+            self.perk_boost_bps = 0;
+        }
+
+        if self.version < 4 {
+            // v4: adds loss_streak_at_entry, carved out of reserved
+            self.loss_streak_at_entry = 0;
+        }
+
+        if self.version < 5 {
+            // v5: adds perk_amount; older perks covered the whole position
+            let has_perk = self.perk_fee_discount_bps > 0 || self.perk_boost_bps > 0;
+            self.perk_amount = if has_perk { self.amount } else { 0 };
+        }
+
+        self.version = Self::VERSION;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Migrate;
+
+    fn position(outcome: u8, amount: u64, version: u8) -> Prediction {
+        Prediction {
+            round_id: 1,
+            user: Pubkey::new_unique(),
+            amount,
+            outcome,
+            timestamp: 0,
+            claimed: false,
+            bump: 255,
+            version,
+            outcome_amounts: [0; MAX_OUTCOMES as usize],
+            perk_fee_discount_bps: 0,
+            perk_boost_bps: 0,
+            loss_streak_at_entry: 0,
+            perk_amount: 0,
+            reserved: [0; 16],
+        }
+    }
+
+    #[test]
+    fn test_top_up_and_hedge() {
+        let mut prediction = position(1, 0, Prediction::VERSION);
+
+        assert_eq!(prediction.add_stake(1, 100).unwrap(), 100);
+        assert_eq!(prediction.add_stake(1, 50).unwrap(), 150);
+        assert_eq!(prediction.add_stake(3, 25).unwrap(), 25);
+        assert!(prediction.add_stake(MAX_OUTCOMES, 1).is_err());
+
+        assert_eq!(prediction.amount, 175);
+        assert_eq!(prediction.stake_on(1), 150);
+        assert!(prediction.is_winner(3));
+        assert!(!prediction.is_winner(0));
+    }
+
+    #[test]
+    fn test_upgrade_fills_single_outcome_stake() {
+        let mut prediction = position(2, 500, 1);
+        prediction.upgrade();
+
+        assert_eq!(prediction.version, Prediction::VERSION);
+        assert_eq!(prediction.stake_on(2), 500);
+        assert_eq!(prediction.outcome_amounts.iter().sum::<u64>(), 500);
+    }
+
+    #[test]
+    fn test_perk_covers_only_its_stake() {
+        let mut prediction = position(0, 0, Prediction::VERSION);
+        prediction.add_stake(0, 100).unwrap();
+        prediction.apply_perk(5000, 100, 100).unwrap();
+        assert!(prediction.apply_perk(10000, 100, 100).is_err());
+
+        // A top-up without the card isn't covered
+        prediction.add_stake(0, 300).unwrap();
+        assert_eq!(prediction.perk_winnings(800), 200);
+
+        // Withdrawing below the covered stake shrinks the cover with it
+        prediction.remove_stake(0, 350).unwrap();
+        assert_eq!(prediction.perk_winnings(100), 100);
+    }
 }
@@ -0,0 +1,132 @@
+// Role-based access control
+
+use anchor_lang::prelude::*;
+use crate::constants::MAX_ROLE_MEMBERS;
+use crate::errors::SocialRouletteError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Role {
+    Pauser,        // Can pause and unpause the program
+    Settler,       // Can close betting, settle and cancel rounds
+    RoundCreator,  // Can create rounds and tournaments
+    Treasurer,     // Can change where platform fees are paid
+}
+
+impl Role {
+    pub const ALL: [Role; 4] = [Role::Pauser, Role::Settler, Role::RoundCreator, Role::Treasurer];
+
+    pub fn mask(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct RoleMember {
+    /// Key holding one or more roles
+    pub key: Pubkey,
+
+    /// Bitmask of granted roles (see `Role::mask`)
+    pub roles: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Roles {
+    /// Keys and the roles they hold
+    #[max_len(MAX_ROLE_MEMBERS)]
+    pub members: Vec<RoleMember>,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version (see `migrate`)
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl Roles {
+    pub const VERSION: u8 = 1;
+
+    pub fn has_role(&self, role: Role, key: &Pubkey) -> bool {
+        self.members
+            .iter()
+            .any(|member| member.key == *key && member.roles & role.mask() != 0)
+    }
+
+    pub fn grant(&mut self, role: Role, key: Pubkey) -> Result<()> {
+        if let Some(member) = self.members.iter_mut().find(|member| member.key == key) {
+            member.roles |= role.mask();
+            return Ok(());
+        }
+
+        require!(
+            self.members.len() < MAX_ROLE_MEMBERS,
+            SocialRouletteError::TooManyRoleMembers
+        );
+
+        self.members.push(RoleMember {
+            key,
+            roles: role.mask(),
+        });
+        Ok(())
+    }
+
+    pub fn revoke(&mut self, role: Role, key: Pubkey) -> Result<()> {
+        require!(self.has_role(role, &key), SocialRouletteError::MissingRole);
+
+        for member in self.members.iter_mut().filter(|member| member.key == key) {
+            member.roles &= !role.mask();
+        }
+
+        // Drop members that no longer hold any role to free up slots
+        self.members.retain(|member| member.roles != 0);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grant_and_revoke() {
+        let ops_bot = Pubkey::new_unique();
+        let mut roles = Roles {
+            members: vec![],
+            bump: 0,
+            version: Roles::VERSION,
+            reserved: [0; 32],
+        };
+
+        roles.grant(Role::Settler, ops_bot).unwrap();
+        roles.grant(Role::RoundCreator, ops_bot).unwrap();
+        assert!(roles.has_role(Role::Settler, &ops_bot));
+        assert!(roles.has_role(Role::RoundCreator, &ops_bot));
+        assert!(!roles.has_role(Role::Treasurer, &ops_bot));
+        assert_eq!(roles.members.len(), 1);
+
+        roles.revoke(Role::Settler, ops_bot).unwrap();
+        assert!(!roles.has_role(Role::Settler, &ops_bot));
+        assert!(roles.revoke(Role::Settler, ops_bot).is_err());
+
+        // Revoking the last role removes the member entirely
+        roles.revoke(Role::RoundCreator, ops_bot).unwrap();
+        assert!(roles.members.is_empty());
+    }
+
+    #[test]
+    fn test_member_limit() {
+        let mut roles = Roles {
+            members: vec![],
+            bump: 0,
+            version: Roles::VERSION,
+            reserved: [0; 32],
+        };
+        for _ in 0..MAX_ROLE_MEMBERS {
+            roles.grant(Role::Pauser, Pubkey::new_unique()).unwrap();
+        }
+        assert!(roles.grant(Role::Pauser, Pubkey::new_unique()).is_err());
+    }
+}
@@ -1,6 +1,7 @@
 // Round state structure
 
 use anchor_lang::prelude::*;
+use crate::constants::{AUTO_ROUND_ID_BASE, MAX_OUTCOMES};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum RoundStatus {
@@ -12,6 +13,12 @@ pub enum RoundStatus {
 
 
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum RoundKind {
+    Categorical, // Pool split among `num_outcomes` buckets, one winner
+    Scalar,      // Short/long pools paid linearly between lower and upper bound
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub enum VerificationMethod {
     PythPrice,        // Use Pyth oracle
@@ -20,6 +27,18 @@ pub enum VerificationMethod {
     SwitchboardVRF,   // Random number
 }
 
+impl VerificationMethod {
+    /// Whether `oracle::resolve_outcome` can settle this method on-chain
+    pub fn is_oracle_resolvable(&self) -> bool {
+        !matches!(self, VerificationMethod::TwitterAPI)
+    }
+    
+    /// Whether the outcome is read from the contents of the oracle account
+    pub fn reads_oracle_account(&self) -> bool {
+        matches!(self, VerificationMethod::PythPrice | VerificationMethod::SwitchboardVRF)
+    }
+}
+
 
 #[account]
 #[derive(InitSpace)]
@@ -80,6 +99,39 @@ pub struct Round {
     /// Authorized oracle that can settle this round
     pub oracle: Pubkey,
 
+    /// Whether all activity on this round is halted (except emergency cancel)
+    pub frozen: bool,
+
+    /// Account layout version (see `migrate`)
+    pub version: u8,
+
+    /// Total amount currently staked on each outcome (lamports)
+    pub outcome_pools: [u64; MAX_OUTCOMES as usize],
+
+    /// Number of outcome token mints created (0 = round is not tokenized)
+    pub outcome_mints_created: u8,
+
+    /// Lamports backing outstanding outcome tokens (separate from `total_pool`)
+    pub token_collateral: u64,
+
+    /// Categorical or scalar payout
+    pub kind: RoundKind,
+
+    /// Scalar rounds: value at or below which shorts take the whole pool
+    pub lower_bound: i64,
+
+    /// Scalar rounds: value at or above which longs take the whole pool
+    pub upper_bound: i64,
+
+    /// Scalar rounds: oracle value recorded at settlement (unclamped)
+    pub resolved_value: i64,
+
+    /// Largest single position placed in the round (lamports)
+    pub largest_stake: u64,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 22],
+
 //     #[max_len(1000)]  // Adjust based on max predictions
 // pub leaderboard: Vec<(Pubkey, u64)>,
     
@@ -87,6 +139,34 @@ pub struct Round {
 
 impl Round {
     pub const UNSET_OUTCOME: u8 = 255;
+    pub const VERSION: u8 = 5;
+    pub const SCALAR_SHORT: u8 = 0;
+    pub const SCALAR_LONG: u8 = 1;
+    
+    /// Id assigned by create_round_auto for auto round counter value `counter`.
+    /// Auto ids run from `AUTO_ROUND_ID_BASE` up to `2 * AUTO_ROUND_ID_BASE`, so
+    /// they fit the backend's signed BIGINT columns and a JS number.
+    pub fn auto_round_id(counter: u64) -> u64 {
+        AUTO_ROUND_ID_BASE.saturating_add(counter)
+    }
+    
+    /// Whether `round_id` is in the range reserved for create_round_auto
+    pub fn is_auto_round_id(round_id: u64) -> bool {
+        (AUTO_ROUND_ID_BASE..2 * AUTO_ROUND_ID_BASE).contains(&round_id)
+    }
+    
+    /// Whether `round_id` may be picked by create_round. Everything from
+    /// `AUTO_ROUND_ID_BASE` up belongs to auto rounds and round series
+    /// (`series_id << 32`), so manual ids can't squat a spawn.
+    pub fn is_manual_round_id(round_id: u64) -> bool {
+        round_id < AUTO_ROUND_ID_BASE
+    }
+    
+    /// Whether settlement must read the round's own `data_source`, so the
+    /// settler can't pick the feed that decides the payout
+    pub fn requires_data_source(&self) -> bool {
+        self.kind == RoundKind::Scalar || self.verification_method.reads_oracle_account()
+    }
     
     pub fn is_betting_active(&self, current_time: i64) -> bool {
         self.status == RoundStatus::Active 
@@ -111,31 +191,145 @@ impl Round {
         self.status == RoundStatus::Cancelled
     }
     
-    pub fn add_prediction(&mut self, amount: u64, outcome: u8) -> Result<()> {
+    /// Whether every outcome has an SPL mint, so complete sets can be minted
+    pub fn outcome_tokens_ready(&self) -> bool {
+        self.outcome_mints_created > 0 && self.outcome_mints_created == self.num_outcomes
+    }
+    
+    /// Lamports paid out when redeeming `amount` winning tokens out of `supply`
+    pub fn calc_token_redemption(&self, amount: u64, supply: u64) -> Result<u64> {
+        require!(supply > 0, crate::errors::SocialRouletteError::NoWinnings);
+        
+        let payout = (amount as u128)
+            .checked_mul(self.token_collateral as u128)
+            .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?
+            / supply as u128;
+        
+        u64::try_from(payout).map_err(|_| error!(crate::errors::SocialRouletteError::ArithmeticOverflow))
+    }
+    
+    /// Lamports refunded for `amount` tokens of any outcome of a cancelled round.
+    /// Each lamport of collateral backs one complete set, so every token is
+    /// worth an equal cut of it.
+    pub fn calc_cancelled_token_refund(&self, amount: u64) -> Result<u64> {
+        require!(self.num_outcomes > 0, crate::errors::SocialRouletteError::InvalidOutcomeCount);
+        
+        let refund = amount / self.num_outcomes as u64;
+        require!(
+            refund <= self.token_collateral,
+            crate::errors::SocialRouletteError::InsufficientVaultBalance
+        );
+        Ok(refund)
+    }
+    
+    pub fn add_token_collateral(&mut self, amount: u64) -> Result<()> {
+        self.token_collateral = self.token_collateral
+            .checked_add(amount)
+            .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?;
+        Ok(())
+    }
+    
+    pub fn remove_token_collateral(&mut self, amount: u64) -> Result<()> {
+        self.token_collateral = self.token_collateral
+            .checked_sub(amount)
+            .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticUnderflow))?;
+        Ok(())
+    }
+    
+    /// Add a stake to the pool; `new_position` counts a new prediction account
+    pub fn add_prediction(&mut self, amount: u64, outcome: u8, new_position: bool) -> Result<()> {
         self.total_pool = self.total_pool
             .checked_add(amount)
             .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?;
         
-        self.total_predictions = self.total_predictions
-            .checked_add(1)
+        let outcome_pool = self.outcome_pools
+            .get_mut(outcome as usize)
+            .ok_or(error!(crate::errors::SocialRouletteError::InvalidOutcome))?;
+        *outcome_pool = outcome_pool
+            .checked_add(amount)
             .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?;
         
+        if new_position {
+            self.total_predictions = self.total_predictions
+                .checked_add(1)
+                .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Track the largest position seen, given a position's new total
+    pub fn record_stake(&mut self, position_amount: u64) {
+        self.largest_stake = self.largest_stake.max(position_amount);
+    }
+    
+    /// Remove a withdrawn stake. The exit fee stays in `total_pool` but no longer
+    /// backs any outcome; `closed_position` uncounts the prediction account.
+    pub fn remove_prediction(
+        &mut self,
+        amount: u64,
+        exit_fee: u64,
+        outcome: u8,
+        closed_position: bool,
+    ) -> Result<()> {
+        let payout = amount
+            .checked_sub(exit_fee)
+            .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticUnderflow))?;
+        
+        self.total_pool = self.total_pool
+            .checked_sub(payout)
+            .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticUnderflow))?;
+        
+        let outcome_pool = self.outcome_pools
+            .get_mut(outcome as usize)
+            .ok_or(error!(crate::errors::SocialRouletteError::InvalidOutcome))?;
+        *outcome_pool = outcome_pool
+            .checked_sub(amount)
+            .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticUnderflow))?;
+        
+        if closed_position {
+            self.total_predictions = self.total_predictions
+                .checked_sub(1)
+                .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticUnderflow))?;
+        }
+        
         Ok(())
     }
     
-    pub fn set_winning_outcome(&mut self, outcome: u8, winning_pool_amount: u64) -> Result<()> {
+    /// Record the winner; the winning pool is the stake tracked on that outcome
+    pub fn set_winning_outcome(&mut self, outcome: u8) -> Result<()> {
     // ✅ Validate outcome hasn't been set already
     require!(
         self.winning_outcome == Self::UNSET_OUTCOME,
         crate::errors::SocialRouletteError::RoundAlreadySettled
     );
     
+    let winning_pool = *self.outcome_pools
+        .get(outcome as usize)
+        .ok_or(error!(crate::errors::SocialRouletteError::InvalidOutcome))?;
+    
     self.winning_outcome = outcome;
-    self.winning_pool = winning_pool_amount;
+    self.winning_pool = winning_pool;
     self.status = RoundStatus::Settled;
     Ok(())
 }
     
+    /// Settle a scalar round at `value`. `winning_outcome` records the side
+    /// receiving the larger share of the pool (long on ties).
+    pub fn settle_scalar(&mut self, value: i64) -> Result<()> {
+        require!(
+            self.kind == RoundKind::Scalar,
+            crate::errors::SocialRouletteError::InvalidRoundKind
+        );
+        
+        let clamped = value.clamp(self.lower_bound, self.upper_bound) as i128;
+        let midpoint = (self.lower_bound as i128 + self.upper_bound as i128) / 2;
+        let side = if clamped >= midpoint { Self::SCALAR_LONG } else { Self::SCALAR_SHORT };
+        
+        self.resolved_value = value;
+        self.set_winning_outcome(side)
+    }
+    
    pub fn close_betting(&mut self) -> Result<()> {
     require!(
         self.status == RoundStatus::Active,
@@ -157,3 +351,56 @@ impl Round {
     Ok(())
 }
 }
+
+impl crate::state::Migrate for Round {
+    const LATEST_VERSION: u8 = Round::VERSION;
+    const SPACE: usize = 8 + Round::INIT_SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Pre-v2 rounds don't track per-outcome stakes, so a round still taking
+    /// or awaiting settlement of stakes would come out with empty pools
+    fn can_upgrade(&self) -> bool {
+        self.version >= 2
+            || self.total_pool == 0
+            || matches!(self.status, RoundStatus::Settled | RoundStatus::Cancelled)
+    }
+
+    fn upgrade(&mut self) {
+        if self.version < 1 {
+            // v1: adds frozen, version and reserved
+            self.frozen = false;
+            self.reserved = [0; 22];
+        }
+
+        if self.version < 2 {
+            // v2: adds outcome_pools; only reached for empty, settled or cancelled
+            // rounds (see `can_upgrade`), whose pools are never read again
+            self.outcome_pools = [0; MAX_OUTCOMES as usize];
+        }
+
+        if self.version < 3 {
+            // v3: adds outcome token bookkeeping carved out of reserved
+            self.outcome_mints_created = 0;
+            self.token_collateral = 0;
+        }
+
+        if self.version < 4 {
+            // v4: adds scalar round fields; existing rounds are categorical
+            self.kind = RoundKind::Categorical;
+            self.lower_bound = 0;
+            self.upper_bound = 0;
+            self.resolved_value = 0;
+        }
+
+        if self.version < 5 {
+            // v5: adds largest_stake; positions placed earlier aren't counted
+            self.largest_stake = 0;
+            self.reserved = [0; 22];
+        }
+
+        self.version = Self::VERSION;
+    }
+}
@@ -0,0 +1,29 @@
+// Creator + client nonce -> program-assigned round id
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct RoundLookup {
+    /// Creator who requested the round
+    pub creator: Pubkey,
+    
+    /// Client-chosen nonce, unique per creator
+    pub client_nonce: u64,
+    
+    /// Round id assigned by the program
+    pub round_id: u64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl RoundLookup {
+    pub const VERSION: u8 = 1;
+}
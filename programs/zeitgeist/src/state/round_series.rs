@@ -0,0 +1,98 @@
+// Recurring round series template
+
+use anchor_lang::prelude::*;
+use crate::constants::SERIES_ROUND_ID_SHIFT;
+use crate::errors::SocialRouletteError;
+use crate::state::VerificationMethod;
+
+#[account]
+#[derive(InitSpace)]
+pub struct RoundSeries {
+    /// Unique series ID (non-zero, below 2^31)
+    pub series_id: u64,
+    
+    /// Creator of the series (recorded as creator of every spawned round)
+    pub creator: Pubkey,
+    
+    /// Question copied into every spawned round
+    #[max_len(200)]
+    pub question: String,
+    
+    /// Number of possible outcomes (2-10)
+    pub num_outcomes: u8,
+    
+    pub verification_method: VerificationMethod,
+    
+    /// Target value for comparison (e.g., price in cents: 15000 = $150.00)
+    pub target_value: i64,
+    
+    /// Data source address (e.g., Pyth price feed pubkey)
+    pub data_source: Pubkey,
+    
+    /// Authorized oracle that can settle spawned rounds
+    pub oracle: Pubkey,
+    
+    /// Seconds from a spawned round's start to its end
+    pub cadence: i64,
+    
+    /// Seconds after start that betting stays open
+    pub betting_window_duration: i64,
+    
+    /// Round id the next spawn will use
+    pub next_round_id: u64,
+    
+    /// Number of rounds spawned so far
+    pub rounds_spawned: u64,
+    
+    /// End time of the most recently spawned round (0 before the first spawn)
+    pub last_end_time: i64,
+    
+    /// Whether new rounds may be spawned
+    pub active: bool,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 64],
+}
+
+impl RoundSeries {
+    pub const VERSION: u8 = 1;
+    pub const MAX_SERIES_ID: u64 = (1 << 31) - 1;
+
+    /// First round id in the range reserved for `series_id`
+    pub fn first_round_id(series_id: u64) -> Result<u64> {
+        require!(
+            series_id > 0 && series_id <= Self::MAX_SERIES_ID,
+            SocialRouletteError::InvalidSeriesId
+        );
+        Ok(series_id << SERIES_ROUND_ID_SHIFT)
+    }
+
+    pub fn can_spawn(&self, current_time: i64) -> bool {
+        self.active && current_time >= self.last_end_time
+    }
+
+    pub fn record_spawn(&mut self, end_time: i64) -> Result<()> {
+        let next_round_id = self.next_round_id
+            .checked_add(1)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+
+        // Never spill into the id range of the next series
+        require!(
+            next_round_id >> SERIES_ROUND_ID_SHIFT == self.series_id,
+            SocialRouletteError::SeriesExhausted
+        );
+
+        self.next_round_id = next_round_id;
+        self.rounds_spawned = self.rounds_spawned
+            .checked_add(1)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        self.last_end_time = end_time;
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+// Season and leaderboard state
+
+use anchor_lang::prelude::*;
+use std::cmp::Ordering;
+use crate::constants::{LEADERBOARD_SIZE, SEASON_REWARD_SHARES_BPS};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct LeaderboardEntry {
+    pub user: Pubkey,
+    
+    /// Profit over the season, with stakes not yet resolved counted as lost (lamports)
+    pub net_profit: i64,
+    
+    /// Wins / positions opened over the season, in basis points; unresolved
+    /// positions count against it
+    pub win_rate_bps: u64,
+    
+    /// Amount wagered over the season (lamports)
+    pub volume: u64,
+}
+
+impl LeaderboardEntry {
+    /// Ranking order: profit, then win rate, then volume (best first)
+    pub fn rank_cmp(&self, other: &Self) -> Ordering {
+        other.net_profit
+            .cmp(&self.net_profit)
+            .then(other.win_rate_bps.cmp(&self.win_rate_bps))
+            .then(other.volume.cmp(&self.volume))
+    }
+}
+
+/// A ranked competition window. Players join to snapshot their `UserStats`
+/// and submit to be ranked on what changed since; once the season closes, the
+/// top `LEADERBOARD_SIZE` each claim their share of `reward_pool`.
+#[account]
+#[derive(InitSpace)]
+pub struct Season {
+    /// Unique season ID
+    pub season_id: u64,
+    
+    /// Season start time (Unix timestamp)
+    pub start_time: i64,
+    
+    /// Submissions close at this time (Unix timestamp)
+    pub end_time: i64,
+    
+    /// All-time leaderboard: players are ranked on their lifetime stats
+    pub global: bool,
+    
+    /// Lamports allocated from the treasury for rewards
+    pub reward_pool: u64,
+    
+    /// Whether the leaderboard is final and rewards can be claimed
+    pub closed: bool,
+    
+    /// Top players, best first
+    #[max_len(LEADERBOARD_SIZE)]
+    pub leaderboard: Vec<LeaderboardEntry>,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Leaderboard ranks whose reward has been claimed (bit per 0-based rank)
+    pub rewards_claimed: u16,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 30],
+}
+
+impl Season {
+    pub const VERSION: u8 = 1;
+    
+    pub fn is_active(&self, current_time: i64) -> bool {
+        !self.closed && current_time >= self.start_time && current_time < self.end_time
+    }
+    
+    /// Insert or update a player's entry, keeping the board sorted and bounded.
+    /// Returns the player's 0-based rank, or None if they didn't make the board.
+    pub fn submit(&mut self, entry: LeaderboardEntry) -> Option<u8> {
+        self.leaderboard.retain(|existing| existing.user != entry.user);
+        
+        let position = self.leaderboard
+            .iter()
+            .position(|existing| entry.rank_cmp(existing) == Ordering::Less)
+            .unwrap_or(self.leaderboard.len());
+        
+        if position >= LEADERBOARD_SIZE {
+            return None;
+        }
+        
+        self.leaderboard.insert(position, entry);
+        self.leaderboard.truncate(LEADERBOARD_SIZE);
+        Some(position as u8)
+    }
+    
+    /// Reward owed to the player at a 0-based leaderboard rank
+    pub fn reward_for(&self, rank: usize) -> Result<u64> {
+        Self::reward_share(self.reward_pool, rank)
+    }
+    
+    /// Total owed to the ranks on the final leaderboard
+    pub fn allocated_rewards(&self) -> Result<u64> {
+        (0..self.leaderboard.len()).try_fold(0u64, |total, rank| {
+            total
+                .checked_add(self.reward_for(rank)?)
+                .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))
+        })
+    }
+    
+    /// Mark `user`'s reward claimed, returning their 0-based rank and reward
+    pub fn claim_reward(&mut self, user: &Pubkey) -> Result<(u8, u64)> {
+        let rank = self.leaderboard
+            .iter()
+            .position(|entry| entry.user == *user)
+            .ok_or(error!(crate::errors::SocialRouletteError::NoSeasonReward))?;
+        
+        let bit = 1u16 << rank;
+        require!(
+            self.rewards_claimed & bit == 0,
+            crate::errors::SocialRouletteError::AlreadyClaimed
+        );
+        self.rewards_claimed |= bit;
+        
+        Ok((rank as u8, self.reward_for(rank)?))
+    }
+    
+    /// Cut of `reward_pool` paid to a 0-based leaderboard rank
+    pub fn reward_share(reward_pool: u64, rank: usize) -> Result<u64> {
+        let share_bps = SEASON_REWARD_SHARES_BPS
+            .get(rank)
+            .ok_or(error!(crate::errors::SocialRouletteError::InvalidRemainingAccounts))?;
+        
+        let reward = (reward_pool as u128)
+            .checked_mul(*share_bps as u128)
+            .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?
+            / 10000;
+        
+        Ok(reward as u64)
+    }
+}
+
+/// A player's `UserStats` snapshot when they joined a season
+#[account]
+#[derive(InitSpace)]
+pub struct SeasonEntry {
+    pub season_id: u64,
+    
+    pub user: Pubkey,
+    
+    pub baseline_net_profit: i64,
+    
+    pub baseline_wins: u64,
+    
+    pub baseline_losses: u64,
+    
+    pub baseline_wagered: u64,
+    
+    pub baseline_pending_stake: u64,
+    
+    pub baseline_predictions: u64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 16],
+}
+
+impl SeasonEntry {
+    pub const VERSION: u8 = 1;
+    
+    /// Leaderboard entry from the change in `stats` since this snapshot.
+    /// Losses are only realized when a losing position is resolved, so stakes
+    /// still pending are scored as lost; leaving losers unresolved can't help.
+    pub fn season_score(&self, stats: &crate::state::UserStats) -> LeaderboardEntry {
+        let wins = stats.total_wins.saturating_sub(self.baseline_wins);
+        let losses = stats.total_losses.saturating_sub(self.baseline_losses);
+        let opened = stats.total_predictions
+            .saturating_sub(self.baseline_predictions)
+            .max(wins + losses);
+        
+        let marked = |net_profit: i64, pending_stake: u64| net_profit as i128 - pending_stake as i128;
+        let net_profit = marked(stats.net_profit, stats.pending_stake)
+            - marked(self.baseline_net_profit, self.baseline_pending_stake);
+        
+        LeaderboardEntry {
+            user: self.user,
+            net_profit: net_profit.clamp(i64::MIN as i128, i64::MAX as i128) as i64,
+            win_rate_bps: crate::utils::calculate_win_rate(wins, opened),
+            volume: stats.total_wagered.saturating_sub(self.baseline_wagered),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reward_shares() {
+        assert_eq!(Season::reward_share(1_000_000, 0).unwrap(), 300_000);
+        assert_eq!(Season::reward_share(1_000_000, LEADERBOARD_SIZE - 1).unwrap(), 15_000);
+        assert!(Season::reward_share(1_000_000, LEADERBOARD_SIZE).is_err());
+
+        // The shares pay out the whole pool
+        let total: u64 = (0..LEADERBOARD_SIZE)
+            .map(|rank| Season::reward_share(1_000_000, rank).unwrap())
+            .sum();
+        assert_eq!(total, 1_000_000);
+    }
+}
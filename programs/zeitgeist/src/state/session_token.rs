@@ -0,0 +1,83 @@
+// Session key delegation state
+
+use anchor_lang::prelude::*;
+use crate::errors::SocialRouletteError;
+
+/// Authorizes `session_key` to place predictions on behalf of `user` until
+/// `expires_at`, up to `spend_cap` lamports in total
+#[account]
+#[derive(InitSpace)]
+pub struct SessionToken {
+    /// User the session acts for
+    pub user: Pubkey,
+    
+    /// Ephemeral key allowed to sign for the user
+    pub session_key: Pubkey,
+    
+    /// Session is invalid from this time (Unix timestamp)
+    pub expires_at: i64,
+    
+    /// Maximum total stake the session key may place (lamports)
+    pub spend_cap: u64,
+    
+    /// Stake placed through this session so far (lamports)
+    pub spent: u64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Account layout version
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 32],
+}
+
+impl SessionToken {
+    pub const VERSION: u8 = 1;
+    
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        current_time >= self.expires_at
+    }
+    
+    /// Charge `amount` against the session's spend cap
+    pub fn record_spend(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        require!(!self.is_expired(current_time), SocialRouletteError::SessionExpired);
+        
+        let spent = self.spent
+            .checked_add(amount)
+            .ok_or(error!(SocialRouletteError::ArithmeticOverflow))?;
+        require!(spent <= self.spend_cap, SocialRouletteError::SessionSpendCapExceeded);
+        
+        self.spent = spent;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_spend() {
+        let mut session = SessionToken {
+            user: Pubkey::new_unique(),
+            session_key: Pubkey::new_unique(),
+            expires_at: 100,
+            spend_cap: 1000,
+            spent: 0,
+            bump: 0,
+            version: SessionToken::VERSION,
+            reserved: [0; 32],
+        };
+        
+        session.record_spend(600, 50).unwrap();
+        assert!(session.record_spend(500, 50).is_err());
+        session.record_spend(400, 99).unwrap();
+        assert_eq!(session.spent, 1000);
+        
+        // Expired sessions can't spend, even within the cap
+        session.spend_cap = 2000;
+        assert!(session.record_spend(1, 100).is_err());
+    }
+}
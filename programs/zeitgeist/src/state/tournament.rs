@@ -44,9 +44,17 @@ pub struct Tournament {
     
     /// Bump seed for PDA derivation
     pub bump: u8,
+
+    /// Account layout version (see `migrate`)
+    pub version: u8,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 64],
 }
 
 impl Tournament {
+    pub const VERSION: u8 = 1;
+    
     pub fn is_active(&self, current_time: i64) -> bool {
         self.status == TournamentStatus::Active && current_time >= self.start_time
     }
@@ -95,3 +103,21 @@ impl Tournament {
         Ok(())
     }
 }
+
+impl crate::state::Migrate for Tournament {
+    const LATEST_VERSION: u8 = Tournament::VERSION;
+    const SPACE: usize = 8 + Tournament::INIT_SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        if self.version < 1 {
+            // v1: adds version and reserved
+            self.reserved = [0; 64];
+        }
+
+        self.version = Self::VERSION;
+    }
+}
@@ -1,6 +1,7 @@
 // User statistics tracking
 
 use anchor_lang::prelude::*;
+use crate::constants::*;
 
 #[account]
 #[derive(InitSpace)]
@@ -20,32 +21,96 @@ pub struct UserStats {
     /// Total amount won (lamports)
     pub total_won: u64,
     
-    /// Net profit/loss (lamports, can be negative)
+    /// Realized profit/loss over resolved positions (lamports, can be negative)
     pub net_profit: i64,
     
     /// Bump seed for PDA derivation
     pub bump: u8,
+
+    /// Account layout version (see `migrate`)
+    pub version: u8,
+
+    /// Stake in positions that haven't been won, lost, refunded or withdrawn (lamports)
+    pub pending_stake: u64,
+
+    /// Total number of losing predictions
+    pub total_losses: u64,
+
+    /// Total amount refunded from cancelled rounds (lamports)
+    pub total_refunded: u64,
+
+    /// Consecutive resolved wins, reset by a loss
+    pub current_streak: u32,
+
+    /// Best win streak ever
+    pub longest_streak: u32,
+
+    /// Experience points (see `level`)
+    pub xp: u64,
+
+    /// Unlocked achievements (`ACHIEVEMENT_*` bitflags)
+    pub achievements: u32,
+
+    /// Day number (Unix time / 86400) of the most recent heatmap bucket
+    pub last_active_day: u32,
+
+    /// New positions opened per day; bucket `day % HEATMAP_DAYS`, saturating
+    pub daily_rounds: [u8; HEATMAP_DAYS],
+
+    /// Consecutive resolved losses, reset by a win
+    pub loss_streak: u32,
+
+    /// Reserved space for future fields
+    pub reserved: [u8; 5],
 }
 
 impl UserStats {
-    pub fn record_prediction(&mut self, amount: u64) -> Result<()> {
-        self.total_predictions = self.total_predictions
-            .checked_add(1)
-            .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?;
+    pub const VERSION: u8 = 4;
+    
+    /// Record a stake; top-ups of an existing position don't count as a new prediction.
+    /// The stake stays pending (and out of `net_profit`) until the position resolves.
+    pub fn record_prediction(&mut self, amount: u64, new_position: bool) -> Result<()> {
+        if new_position {
+            self.total_predictions = self.total_predictions
+                .checked_add(1)
+                .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?;
+        }
         
         self.total_wagered = self.total_wagered
             .checked_add(amount)
             .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?;
         
-        // Update net profit (subtract bet)
-        self.net_profit = self.net_profit
-            .checked_sub(amount as i64)
-            .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticUnderflow))?;
+        self.pending_stake = self.pending_stake
+            .checked_add(amount)
+            .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?;
         
         Ok(())
     }
     
-    pub fn record_win(&mut self, winnings: u64) -> Result<()> {
+    /// Record an early withdrawal; the exit fee is a realized loss
+    pub fn record_withdrawal(&mut self, amount: u64, exit_fee: u64, closed_position: bool) -> Result<()> {
+        self.uncount_prediction(amount, closed_position)?;
+        
+        let payout = amount
+            .checked_sub(exit_fee)
+            .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticUnderflow))?;
+        self.realize(amount, payout)
+    }
+    
+    /// Record a refunded position; it no longer counts as a prediction or wager
+    pub fn record_refund(&mut self, amount: u64) -> Result<()> {
+        self.uncount_prediction(amount, true)?;
+        
+        self.total_refunded = self.total_refunded
+            .checked_add(amount)
+            .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?;
+        
+        self.realize(amount, amount)
+    }
+    
+    /// Record a resolved position that paid `winnings` (gross, stake included).
+    /// Returns newly unlocked achievements.
+    pub fn record_win(&mut self, stake: u64, winnings: u64) -> Result<u32> {
         self.total_wins = self.total_wins
             .checked_add(1)
             .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?;
@@ -54,11 +119,132 @@ impl UserStats {
             .checked_add(winnings)
             .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?;
         
-        // Update net profit (add winnings)
-        self.net_profit = self.net_profit
-            .checked_add(winnings as i64)
+        self.realize(stake, winnings)?;
+        
+        self.current_streak = self.current_streak.saturating_add(1);
+        self.loss_streak = 0;
+        self.longest_streak = self.longest_streak.max(self.current_streak);
+        self.add_xp(XP_PER_WIN);
+        
+        let mut unlocked = self.unlock(ACHIEVEMENT_FIRST_WIN);
+        if self.current_streak >= STREAK_ACHIEVEMENT_LENGTH {
+            unlocked |= self.unlock(ACHIEVEMENT_STREAK_10);
+        }
+        Ok(unlocked)
+    }
+    
+    /// Record a resolved position that paid nothing
+    pub fn record_loss(&mut self, stake: u64) -> Result<()> {
+        self.total_losses = self.total_losses
+            .checked_add(1)
             .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?;
         
+        self.realize(stake, 0)?;
+        
+        self.current_streak = 0;
+        self.loss_streak = self.loss_streak.saturating_add(1);
+        self.add_xp(XP_PER_LOSS);
+        Ok(())
+    }
+    
+    /// Record a tournament victory. Returns newly unlocked achievements.
+    pub fn record_tournament_win(&mut self) -> u32 {
+        self.add_xp(XP_PER_TOURNAMENT_WIN);
+        self.unlock(ACHIEVEMENT_TOURNAMENT_CHAMPION)
+    }
+    
+    /// Count a newly opened position in today's heatmap bucket and award XP
+    pub fn record_activity(&mut self, current_time: i64) {
+        let day = (current_time.max(0) / SECONDS_PER_DAY) as u32;
+        
+        // Clear buckets for the days skipped since the last activity
+        let elapsed = day.saturating_sub(self.last_active_day) as usize;
+        for offset in 1..=elapsed.min(HEATMAP_DAYS) {
+            let skipped = self.last_active_day as usize + offset;
+            self.daily_rounds[skipped % HEATMAP_DAYS] = 0;
+        }
+        self.last_active_day = self.last_active_day.max(day);
+        
+        let bucket = &mut self.daily_rounds[day as usize % HEATMAP_DAYS];
+        *bucket = bucket.saturating_add(1);
+        
+        self.add_xp(XP_PER_PREDICTION);
+    }
+    
+    /// Set an achievement flag, returning it if it wasn't already set
+    pub fn unlock(&mut self, achievement: u32) -> u32 {
+        let newly = achievement & !self.achievements;
+        self.achievements |= achievement;
+        newly
+    }
+    
+    /// Level derived from XP
+    pub fn level(&self) -> u32 {
+        Self::level_for_xp(self.xp)
+    }
+    
+    /// Level reached at `xp`: 1 at 0 XP, 2 at 100, 3 at 400, 4 at 900, ...
+    pub fn level_for_xp(xp: u64) -> u32 {
+        // Integer square root of xp / XP_PER_LEVEL_BASE by binary search
+        let units = xp / XP_PER_LEVEL_BASE;
+        let (mut low, mut high) = (0u64, u32::MAX as u64);
+        while low < high {
+            let mid = (low + high).div_ceil(2);
+            if mid * mid <= units {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        low as u32 + 1
+    }
+    
+    fn add_xp(&mut self, amount: u64) {
+        self.xp = self.xp.saturating_add(amount);
+    }
+    
+    /// Emit `AchievementUnlocked` for each flag in `unlocked`
+    pub fn emit_unlocked(&self, unlocked: u32, timestamp: i64) {
+        for bit in 0..u32::BITS {
+            let achievement = 1 << bit;
+            if unlocked & achievement != 0 {
+                emit!(crate::events::AchievementUnlocked {
+                    user: self.user,
+                    achievement,
+                    xp: self.xp,
+                    level: self.level(),
+                    timestamp,
+                });
+            }
+        }
+    }
+    
+    /// Take a position's stake out of the prediction and wager totals
+    fn uncount_prediction(&mut self, amount: u64, closed_position: bool) -> Result<()> {
+        if closed_position {
+            self.total_predictions = self.total_predictions
+                .checked_sub(1)
+                .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticUnderflow))?;
+        }
+        
+        self.total_wagered = self.total_wagered
+            .checked_sub(amount)
+            .ok_or(error!(crate::errors::SocialRouletteError::ArithmeticUnderflow))?;
+        
+        Ok(())
+    }
+    
+    /// Release `stake` from pending and book `payout - stake` into `net_profit`.
+    /// Stakes placed before v2 were charged to `net_profit` up front and were
+    /// never pending, so only the part actually found in `pending_stake` is charged.
+    fn realize(&mut self, stake: u64, payout: u64) -> Result<()> {
+        let released = stake.min(self.pending_stake);
+        self.pending_stake -= released;
+        
+        self.net_profit = (self.net_profit as i128 + payout as i128 - released as i128)
+            .try_into()
+            .map_err(|_| error!(crate::errors::SocialRouletteError::ArithmeticOverflow))?;
+        
         Ok(())
     }
     
@@ -69,4 +255,81 @@ impl UserStats {
         
         ((self.total_wins as u128 * 10000) / self.total_predictions as u128) as u64
     }
+    
+    /// Snapshot for the `UserStatsUpdated` event, emitted after every change
+    pub fn updated_event(&self) -> crate::events::UserStatsUpdated {
+        crate::events::UserStatsUpdated {
+            user: self.user,
+            total_predictions: self.total_predictions,
+            total_wagered: self.total_wagered,
+            total_won: self.total_won,
+            win_rate: self.win_rate_bps(),
+            total_wins: self.total_wins,
+            total_losses: self.total_losses,
+            total_refunded: self.total_refunded,
+            pending_stake: self.pending_stake,
+            net_profit: self.net_profit,
+            current_streak: self.current_streak,
+            longest_streak: self.longest_streak,
+            xp: self.xp,
+            level: self.level(),
+            achievements: self.achievements,
+        }
+    }
+}
+
+impl crate::state::Migrate for UserStats {
+    const LATEST_VERSION: u8 = UserStats::VERSION;
+    const SPACE: usize = 8 + UserStats::INIT_SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        if self.version < 1 {
+            // v1: adds version and reserved
+            self.reserved = [0; 5];
+        }
+
+        if self.version < 2 {
+            // v2: adds pending stake, losses and refunds. Open stakes placed
+            // earlier were already charged to net_profit; see `realize`.
+            self.pending_stake = 0;
+            self.total_losses = 0;
+            self.total_refunded = 0;
+        }
+
+        if self.version < 3 {
+            // v3: adds streaks, XP, achievements and the activity heatmap
+            self.current_streak = 0;
+            self.longest_streak = 0;
+            self.xp = 0;
+            self.achievements = 0;
+            self.last_active_day = 0;
+            self.daily_rounds = [0; HEATMAP_DAYS];
+        }
+
+        if self.version < 4 {
+            // v4: adds loss_streak, carved out of reserved
+            self.loss_streak = 0;
+            self.reserved = [0; 5];
+        }
+
+        self.version = Self::VERSION;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_for_xp() {
+        assert_eq!(UserStats::level_for_xp(0), 1);
+        assert_eq!(UserStats::level_for_xp(99), 1);
+        assert_eq!(UserStats::level_for_xp(100), 2);
+        assert_eq!(UserStats::level_for_xp(900), 4);
+        assert_eq!(UserStats::level_for_xp(u64::MAX), 429_496_730);
+    }
 }
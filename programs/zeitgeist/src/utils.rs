@@ -1,6 +1,7 @@
 // Utility functions for Social Roulette
 
 use anchor_lang::prelude::*;
+use crate::constants::*;
 use crate::errors::SocialRouletteError;
 
 /// Calculate platform fee from amount
@@ -39,6 +40,138 @@ pub fn calculate_winnings(
     Ok(winnings as u64)
 }
 
+/// Calculate a scalar round payout. The distributable pool is split between
+/// the long and short pools linearly by where `resolved_value` falls in
+/// `[lower_bound, upper_bound]` (clamped); each side is then shared pro rata
+/// via `calculate_winnings`. If one side is empty the other takes everything.
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_scalar_winnings(
+    long_bet: u64,
+    short_bet: u64,
+    long_pool: u64,
+    short_pool: u64,
+    total_pool: u64,
+    platform_fee: u64,
+    resolved_value: i64,
+    lower_bound: i64,
+    upper_bound: i64,
+) -> Result<u64> {
+    require!(lower_bound < upper_bound, SocialRouletteError::InvalidScalarBounds);
+    
+    let distributable_pool = total_pool
+        .checked_sub(platform_fee)
+        .ok_or(SocialRouletteError::ArithmeticUnderflow)?;
+    
+    let long_payout_pool = if short_pool == 0 {
+        distributable_pool
+    } else if long_pool == 0 {
+        0
+    } else {
+        let position = resolved_value.clamp(lower_bound, upper_bound) as i128 - lower_bound as i128;
+        let range = upper_bound as i128 - lower_bound as i128;
+        (distributable_pool as i128 * position / range) as u64
+    };
+    let short_payout_pool = distributable_pool - long_payout_pool;
+    
+    let long_winnings = calculate_winnings(long_bet, long_pool, long_payout_pool, 0)?;
+    let short_winnings = calculate_winnings(short_bet, short_pool, short_payout_pool, 0)?;
+    
+    long_winnings
+        .checked_add(short_winnings)
+        .ok_or(SocialRouletteError::ArithmeticOverflow.into())
+}
+
+/// Calculate what a prediction is owed from a settled round: its stake on the
+/// winning outcome for categorical rounds, both sides for scalar rounds
+pub fn calculate_prediction_winnings(
+    round: &crate::state::Round,
+    prediction: &crate::state::Prediction,
+) -> Result<u64> {
+    use crate::state::{Round, RoundKind};
+    
+    match round.kind {
+        // Both sides of a scalar round are paid by where the resolved value landed
+        RoundKind::Scalar => calculate_scalar_winnings(
+            prediction.stake_on(Round::SCALAR_LONG),
+            prediction.stake_on(Round::SCALAR_SHORT),
+            round.outcome_pools[Round::SCALAR_LONG as usize],
+            round.outcome_pools[Round::SCALAR_SHORT as usize],
+            round.total_pool,
+            round.platform_fee_collected,
+            round.resolved_value,
+            round.lower_bound,
+            round.upper_bound,
+        ),
+        // Only the stake on the winning outcome pays out
+        RoundKind::Categorical => calculate_winnings(
+            prediction.stake_on(round.winning_outcome),
+            round.winning_pool,
+            round.total_pool,
+            round.platform_fee_collected,
+        ),
+    }
+}
+
+/// Calculate the moment card perk bonus on a winning payout: the rebated share
+/// of the platform fee carried by `winnings`, plus the boost on `winnings`
+pub fn calculate_perk_bonus(
+    winnings: u64,
+    total_pool: u64,
+    platform_fee: u64,
+    fee_discount_bps: u16,
+    boost_bps: u16,
+) -> Result<u64> {
+    let distributable_pool = total_pool
+        .checked_sub(platform_fee)
+        .ok_or(SocialRouletteError::ArithmeticUnderflow)?;
+    
+    // Fee carried by this payout, in proportion to its share of the distributable pool
+    let fee_share = if distributable_pool == 0 {
+        0
+    } else {
+        (winnings as u128)
+            .checked_mul(platform_fee as u128)
+            .ok_or(SocialRouletteError::ArithmeticOverflow)?
+            / distributable_pool as u128
+    };
+    
+    let rebate = fee_share
+        .checked_mul(fee_discount_bps as u128)
+        .ok_or(SocialRouletteError::ArithmeticOverflow)?
+        / 10000;
+    let boost = (winnings as u128)
+        .checked_mul(boost_bps as u128)
+        .ok_or(SocialRouletteError::ArithmeticOverflow)?
+        / 10000;
+    
+    u64::try_from(rebate + boost).map_err(|_| SocialRouletteError::ArithmeticOverflow.into())
+}
+
+/// Calculate the parimutuel payout multiplier for an outcome after platform fees,
+/// in basis points (10000 = 1x). Returns 0 when nothing is staked on the outcome.
+pub fn calculate_implied_multiplier_bps(
+    outcome_pool: u64,
+    total_pool: u64,
+    fee_bps: u16,
+) -> Result<u64> {
+    if outcome_pool == 0 {
+        return Ok(0);
+    }
+    
+    let platform_fee = calculate_platform_fee(total_pool, fee_bps)?;
+    let distributable_pool = total_pool
+        .checked_sub(platform_fee)
+        .ok_or(SocialRouletteError::ArithmeticUnderflow)?;
+    
+    let multiplier = (distributable_pool as u128)
+        .checked_mul(10000)
+        .ok_or(SocialRouletteError::ArithmeticOverflow)?
+        .checked_div(outcome_pool as u128)
+        .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+    
+    Ok(u64::try_from(multiplier).map_err(|_| SocialRouletteError::ArithmeticOverflow)?)
+}
+
 /// Calculate win rate in basis points (0-10000)
 pub fn calculate_win_rate(wins: u64, total: u64) -> u64 {
     if total == 0 {
@@ -48,6 +181,16 @@ pub fn calculate_win_rate(wins: u64, total: u64) -> u64 {
     ((wins as u128 * 10000) / total as u128) as u64
 }
 
+/// Implied odds of the winning outcome in basis points: its share of the pool.
+/// An empty pool carries no information, so it counts as a sure thing (10000).
+pub fn calculate_implied_odds_bps(winning_pool: u64, total_pool: u64) -> u64 {
+    if total_pool == 0 {
+        return 10000;
+    }
+    
+    (((winning_pool as u128 * 10000) / total_pool as u128) as u64).min(10000)
+}
+
 /// Validate timestamp is in the future
 pub fn validate_future_timestamp(timestamp: i64, current_time: i64) -> Result<()> {
     require!(
@@ -71,10 +214,99 @@ pub fn validate_betting_duration(start_time: i64, end_time: i64, min: i64, max:
     Ok(())
 }
 
+/// Validate a new round's schedule and outcome count, returning its betting close time
+pub fn validate_round_schedule(
+    start_time: i64,
+    end_time: i64,
+    num_outcomes: u8,
+    betting_window_duration: i64,
+    current_time: i64,
+) -> Result<i64> {
+    require!(
+        (2..=MAX_OUTCOMES).contains(&num_outcomes),
+        SocialRouletteError::InvalidOutcomeCount
+    );
+
+    validate_future_timestamp(start_time, current_time)?;
+    validate_betting_duration(start_time, end_time, MIN_BETTING_DURATION, MAX_BETTING_DURATION)?;
+
+    require!(
+        (MIN_BETTING_WINDOW_DURATION..=MAX_BETTING_WINDOW_DURATION).contains(&betting_window_duration),
+        SocialRouletteError::InvalidBettingWindowDuration
+    );
+
+    let betting_close_time = start_time
+        .checked_add(betting_window_duration)
+        .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+
+    Ok(betting_close_time)
+}
+
+/// Move lamports out of an account owned by this program (e.g. a `Balance`),
+/// which the system program can't debit
+pub fn transfer_from_program_account(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    let from_lamports = from.lamports()
+        .checked_sub(amount)
+        .ok_or(SocialRouletteError::ArithmeticUnderflow)?;
+    let to_lamports = to.lamports()
+        .checked_add(amount)
+        .ok_or(SocialRouletteError::ArithmeticOverflow)?;
+    
+    **from.try_borrow_mut_lamports()? = from_lamports;
+    **to.try_borrow_mut_lamports()? = to_lamports;
+    
+    Ok(())
+}
+
+/// Validate `(outcome mint, token account)` pairs passed as remaining accounts,
+/// one pair per outcome in order
+pub fn validate_outcome_token_accounts<'info>(
+    program_id: &Pubkey,
+    round_id: u64,
+    num_outcomes: u8,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    require!(
+        accounts.len() == num_outcomes as usize * 2,
+        SocialRouletteError::InvalidTokenAccount
+    );
+
+    for (outcome, pair) in accounts.chunks(2).enumerate() {
+        let (mint, _) = Pubkey::find_program_address(
+            &[OUTCOME_MINT_SEED, round_id.to_le_bytes().as_ref(), &[outcome as u8]],
+            program_id,
+        );
+        require_keys_eq!(pair[0].key(), mint, SocialRouletteError::InvalidOutcomeMint);
+
+        let token_account = Account::<anchor_spl::token::TokenAccount>::try_from(&pair[1])?;
+        require_keys_eq!(token_account.mint, mint, SocialRouletteError::InvalidTokenAccount);
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_calculate_scalar_winnings() {
+        // Pool of 1000 (no fee), 600 long / 400 short, resolved 75% of the way up
+        // Long side gets 750, short side 250
+        assert_eq!(calculate_scalar_winnings(600, 0, 600, 400, 1000, 0, 175, 100, 200).unwrap(), 750);
+        assert_eq!(calculate_scalar_winnings(0, 400, 600, 400, 1000, 0, 175, 100, 200).unwrap(), 250);
+        
+        // Hedged position gets both shares
+        assert_eq!(calculate_scalar_winnings(300, 200, 600, 400, 1000, 0, 175, 100, 200).unwrap(), 375 + 125);
+        
+        // Values outside the range are clamped
+        assert_eq!(calculate_scalar_winnings(600, 0, 600, 400, 1000, 0, 500, 100, 200).unwrap(), 1000);
+        assert_eq!(calculate_scalar_winnings(0, 400, 600, 400, 1000, 0, -50, 100, 200).unwrap(), 1000);
+        
+        // An empty side forfeits its share to the other
+        assert_eq!(calculate_scalar_winnings(0, 400, 0, 400, 400, 0, 200, 100, 200).unwrap(), 400);
+    }
+
     #[test]
     fn test_calculate_platform_fee() {
         // 2% of 1000 = 20
@@ -98,6 +330,18 @@ mod tests {
         assert_eq!(calculate_winnings(500, 1000, 10000, 200).unwrap(), 4900);
     }
 
+    #[test]
+    fn test_calculate_implied_multiplier_bps() {
+        // Pool 10000, 2% fee -> 9800 distributable; 2500 on outcome -> 3.92x
+        assert_eq!(calculate_implied_multiplier_bps(2500, 10000, 200).unwrap(), 39200);
+        
+        // Everyone on one outcome gets back their stake minus the fee
+        assert_eq!(calculate_implied_multiplier_bps(10000, 10000, 200).unwrap(), 9800);
+        
+        // Empty outcome has no defined multiplier
+        assert_eq!(calculate_implied_multiplier_bps(0, 10000, 200).unwrap(), 0);
+    }
+
     #[test]
     fn test_calculate_win_rate() {
         // 75 wins out of 100 = 7500 basis points (75%)
@@ -112,4 +356,24 @@ mod tests {
         // No predictions = 0%
         assert_eq!(calculate_win_rate(0, 0), 0);
     }
+
+    #[test]
+    fn test_calculate_perk_bonus() {
+        // Pool of 10000 with a 200 fee; a 4900 payout carried 200 * 4900 / 9800 = 100 of it
+        assert_eq!(calculate_perk_bonus(4900, 10000, 200, 5000, 0).unwrap(), 50);
+        assert_eq!(calculate_perk_bonus(4900, 10000, 200, 10000, 0).unwrap(), 100);
+        
+        // Boost is on the payout itself
+        assert_eq!(calculate_perk_bonus(4900, 10000, 200, 10000, 100).unwrap(), 149);
+        
+        // No perk, no bonus
+        assert_eq!(calculate_perk_bonus(4900, 10000, 200, 0, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_calculate_implied_odds_bps() {
+        assert_eq!(calculate_implied_odds_bps(0, 0), 10000);
+        assert_eq!(calculate_implied_odds_bps(25, 100), 2500);
+        assert_eq!(calculate_implied_odds_bps(u64::MAX, u64::MAX), 10000);
+    }
 }
@@ -0,0 +1,136 @@
+// AMM markets
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use zeitgeist::constants::{AMM_MARKET_SEED, AMM_POSITION_SEED, AMM_VAULT_SEED, PLATFORM_FEE_BPS, SETTLEMENT_TIMEOUT};
+use zeitgeist::state::{AmmMarket, AmmPosition, VerificationMethod};
+use zeitgeist::utils::calculate_platform_fee;
+use zeitgeist::{accounts, instruction};
+
+use crate::{global_state, pda, roles, Program, ROUND_DURATION, SOL};
+
+fn market(market_id: u64) -> Pubkey {
+    pda(&[AMM_MARKET_SEED, &market_id.to_le_bytes()])
+}
+
+fn amm_vault(market_id: u64) -> Pubkey {
+    pda(&[AMM_VAULT_SEED, &market_id.to_le_bytes()])
+}
+
+fn position(market_id: u64, user: &Pubkey) -> Pubkey {
+    pda(&[AMM_POSITION_SEED, &market_id.to_le_bytes(), user.as_ref()])
+}
+
+impl Program {
+    fn create_amm_market(&mut self, market_id: u64, initial_liquidity: u64) {
+        let now = self.bank.now();
+        self.bank
+            .process(
+                accounts::CreateAmmMarket {
+                    global_state: global_state(),
+                    roles: roles(),
+                    market: market(market_id),
+                    vault: amm_vault(market_id),
+                    creator: self.admin,
+                    system_program: system_program::ID,
+                },
+                instruction::CreateAmmMarket {
+                    market_id,
+                    description: format!("Market {market_id}"),
+                    num_outcomes: 2,
+                    verification_method: VerificationMethod::OnChainData,
+                    target_value: 100,
+                    data_source: Pubkey::default(),
+                    oracle: self.admin,
+                    trading_close_time: now + ROUND_DURATION,
+                    end_time: now + ROUND_DURATION,
+                    initial_liquidity,
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    fn buy_amm_shares(&mut self, market_id: u64, user: Pubkey, outcome: u8, amount: u64) {
+        self.bank
+            .process(
+                accounts::BuyAmmShares {
+                    global_state: global_state(),
+                    market: market(market_id),
+                    position: position(market_id, &user),
+                    vault: amm_vault(market_id),
+                    platform_wallet: self.platform_wallet,
+                    user,
+                    system_program: system_program::ID,
+                },
+                instruction::BuyAmmShares { market_id, outcome, amount, min_shares_out: 0 },
+                &[],
+            )
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_expired_market_cancels_without_moving_value() {
+    let mut program = Program::initialize();
+    let (alice, stranger) = (program.bank.wallet(), program.bank.wallet());
+    program.create_amm_market(1, SOL);
+    program.buy_amm_shares(1, alice, 0, SOL / 2);
+
+    // Once nobody settled in time, anyone may cancel
+    let end_time = program.bank.get::<AmmMarket>(&market(1)).end_time;
+    program.bank.set_time(end_time + SETTLEMENT_TIMEOUT);
+    program
+        .bank
+        .process(
+            accounts::CancelAmmMarket {
+                global_state: global_state(),
+                roles: roles(),
+                market: market(1),
+                authority: stranger,
+            },
+            instruction::CancelAmmMarket { market_id: 1, reason: "expired".to_string() },
+            &[],
+        )
+        .unwrap();
+
+    // Alice gets back everything she paid in except the platform fee
+    let position_rent = Rent::default().minimum_balance(8 + AmmPosition::INIT_SPACE);
+    let before = program.bank.lamports(&alice);
+    program
+        .bank
+        .process(
+            accounts::RedeemAmmShares {
+                global_state: global_state(),
+                market: market(1),
+                position: position(1, &alice),
+                vault: amm_vault(1),
+                user: alice,
+                system_program: system_program::ID,
+            },
+            instruction::RedeemAmmShares { market_id: 1 },
+            &[],
+        )
+        .unwrap();
+    let fee = calculate_platform_fee(SOL / 2, PLATFORM_FEE_BPS).unwrap();
+    assert_eq!(program.bank.lamports(&alice) - before, SOL / 2 - fee + position_rent);
+
+    // The creator gets back exactly their liquidity
+    let before = program.bank.lamports(&program.admin);
+    program
+        .bank
+        .process(
+            accounts::WithdrawAmmLiquidity {
+                global_state: global_state(),
+                market: market(1),
+                vault: amm_vault(1),
+                creator: program.admin,
+                system_program: system_program::ID,
+            },
+            instruction::WithdrawAmmLiquidity { market_id: 1 },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(program.bank.lamports(&program.admin) - before, SOL);
+    assert_eq!(program.bank.lamports(&amm_vault(1)), Rent::default().minimum_balance(0));
+}
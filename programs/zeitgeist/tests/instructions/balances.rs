@@ -0,0 +1,90 @@
+// Custodial balances
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::system_program;
+use zeitgeist::constants::BALANCE_SEED;
+use zeitgeist::errors::SocialRouletteError;
+use zeitgeist::state::{Balance, Prediction, UserStats};
+use zeitgeist::{accounts, instruction};
+
+use crate::harness::program_error;
+use crate::{pda, prediction, vault, Funding, Program, SOL};
+
+fn balance(user: &Pubkey) -> Pubkey {
+    pda(&[BALANCE_SEED, user.as_ref()])
+}
+
+impl Program {
+    fn deposit_balance(&mut self, user: Pubkey, amount: u64) {
+        self.bank
+            .process(
+                accounts::DepositBalance { balance: balance(&user), user, system_program: system_program::ID },
+                instruction::DepositBalance { amount },
+                &[],
+            )
+            .unwrap();
+    }
+
+    fn withdraw_balance(&mut self, user: Pubkey, amount: u64) -> ProgramResult {
+        self.bank.process(
+            accounts::WithdrawBalance { balance: balance(&user), user },
+            instruction::WithdrawBalance { amount },
+            &[],
+        )
+    }
+}
+
+#[test]
+fn test_deposit_and_withdraw_round_trip() {
+    let mut program = Program::initialize();
+    let alice = program.bank.wallet();
+    let balance_rent = Rent::default().minimum_balance(8 + Balance::INIT_SPACE);
+
+    let start = program.bank.lamports(&alice);
+    program.deposit_balance(alice, 5 * SOL);
+    assert_eq!(program.bank.get::<Balance>(&balance(&alice)).amount, 5 * SOL);
+    assert_eq!(program.bank.lamports(&balance(&alice)), balance_rent + 5 * SOL);
+
+    program.withdraw_balance(alice, 2 * SOL).unwrap();
+    assert_eq!(
+        program.withdraw_balance(alice, 3 * SOL + 1),
+        Err(program_error(SocialRouletteError::InsufficientBalance))
+    );
+    program.withdraw_balance(alice, 3 * SOL).unwrap();
+
+    // Everything comes back except the account's rent
+    let held: Balance = program.bank.get(&balance(&alice));
+    assert_eq!((held.amount, held.total_deposited, held.total_withdrawn), (0, 5 * SOL, 5 * SOL));
+    assert_eq!(program.bank.lamports(&balance(&alice)), balance_rent);
+    assert_eq!(start - program.bank.lamports(&alice), balance_rent);
+}
+
+#[test]
+fn test_balance_funded_bet_debits_the_balance() {
+    let mut program = Program::initialize();
+    let alice = program.bank.wallet();
+    program.deposit_balance(alice, 5 * SOL);
+    program.create_slot_round(1, 100);
+
+    let from_balance = Funding { payer: alice, session_token: None, balance: Some(balance(&alice)) };
+    let wallet_before = program.bank.lamports(&alice);
+    let balance_before = program.bank.lamports(&balance(&alice));
+    let vault_before = program.bank.lamports(&vault(1));
+    program.place_prediction_funded(1, alice, from_balance, 0, 2 * SOL).unwrap();
+
+    // The stake moves from the balance; the wallet only pays the new accounts' rent
+    let rent = Rent::default().minimum_balance(8 + Prediction::INIT_SPACE)
+        + Rent::default().minimum_balance(8 + UserStats::INIT_SPACE);
+    assert_eq!(program.bank.get::<Balance>(&balance(&alice)).amount, 3 * SOL);
+    assert_eq!(balance_before - program.bank.lamports(&balance(&alice)), 2 * SOL);
+    assert_eq!(program.bank.lamports(&vault(1)) - vault_before, 2 * SOL);
+    assert_eq!(wallet_before - program.bank.lamports(&alice), rent);
+    assert_eq!(program.bank.get::<Prediction>(&prediction(1, &alice)).amount, 2 * SOL);
+
+    let from_balance = Funding { payer: alice, session_token: None, balance: Some(balance(&alice)) };
+    assert_eq!(
+        program.place_prediction_funded(1, alice, from_balance, 0, 3 * SOL + 1),
+        Err(program_error(SocialRouletteError::InsufficientBalance))
+    );
+}
@@ -0,0 +1,90 @@
+// Winnings crank
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use anchor_lang::solana_program::system_program;
+use zeitgeist::errors::SocialRouletteError;
+use zeitgeist::state::{Prediction, Round, UserStats};
+use zeitgeist::utils::calculate_winnings;
+use zeitgeist::{accounts, instruction};
+
+use crate::harness::program_error;
+use crate::{global_state, prediction, round, user_stats, vault, Program, SOL};
+
+impl Program {
+    pub fn distribute_winnings(&mut self, round_id: u64, users: &[Pubkey]) -> ProgramResult {
+        let remaining: Vec<AccountMeta> = users
+            .iter()
+            .flat_map(|user| {
+                [
+                    AccountMeta::new(prediction(round_id, user), false),
+                    AccountMeta::new(user_stats(user), false),
+                    AccountMeta::new(*user, false),
+                ]
+            })
+            .collect();
+        self.bank.process(
+            accounts::DistributeWinnings {
+                global_state: global_state(),
+                round: round(round_id),
+                vault: vault(round_id),
+                perk_vault: None,
+                system_program: system_program::ID,
+            },
+            instruction::DistributeWinnings { round_id },
+            &remaining,
+        )
+    }
+
+    pub fn close_prediction(&mut self, round_id: u64, user: Pubkey) -> ProgramResult {
+        self.bank.process(
+            accounts::ClosePrediction { prediction: prediction(round_id, &user), user },
+            instruction::ClosePrediction { round_id },
+            &[],
+        )
+    }
+}
+
+#[test]
+fn test_crank_pays_winners_and_keeps_predictions_open() {
+    let mut program = Program::initialize();
+    let (alice, bob) = (program.bank.wallet(), program.bank.wallet());
+    program.create_slot_round(1, 100);
+    program.place_prediction(1, alice, 0, SOL).unwrap();
+    program.place_prediction(1, bob, 1, 3 * SOL).unwrap();
+    program.close_betting(1);
+    program.bank.set_slot(100);
+    program.settle_round(1, Pubkey::default()).unwrap();
+
+    // Unresolved predictions can't be closed out from under the crank
+    assert_eq!(
+        program.close_prediction(1, alice),
+        Err(program_error(SocialRouletteError::PredictionNotClaimed))
+    );
+
+    let fee = program.bank.get::<Round>(&round(1)).platform_fee_collected;
+    let winnings = calculate_winnings(SOL, SOL, 4 * SOL, fee).unwrap();
+    let before = (program.bank.lamports(&alice), program.bank.lamports(&bob));
+    program.distribute_winnings(1, &[alice, bob]).unwrap();
+    assert_eq!(program.bank.lamports(&alice) - before.0, winnings);
+    assert_eq!(program.bank.lamports(&bob), before.1);
+
+    // Both positions are resolved but left open for their moment cards
+    for user in [alice, bob] {
+        assert!(program.bank.get::<Prediction>(&prediction(1, &user)).claimed);
+    }
+    let (alice_stats, bob_stats): (UserStats, UserStats) =
+        (program.bank.get(&user_stats(&alice)), program.bank.get(&user_stats(&bob)));
+    assert_eq!((alice_stats.total_wins, alice_stats.pending_stake), (1, 0));
+    assert_eq!((bob_stats.total_losses, bob_stats.pending_stake), (1, 0));
+
+    // A second pass skips them
+    program.distribute_winnings(1, &[alice, bob]).unwrap();
+    assert_eq!(program.bank.lamports(&alice) - before.0, winnings);
+
+    let prediction_rent = program.bank.lamports(&prediction(1, &bob));
+    program.close_prediction(1, bob).unwrap();
+    assert_eq!(program.bank.lamports(&bob), before.1 + prediction_rent);
+    assert!(!program.bank.exists(&prediction(1, &bob)));
+}
@@ -0,0 +1,384 @@
+// Instruction test harness
+//
+// Runs instructions through the program's real entrypoint, with account
+// constraints and handlers as deployed. The clock, rent and system program
+// CPIs are provided by syscall stubs and SPL Token CPIs run the token
+// program's own processor; any other CPI fails the instruction.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE, SUCCESS};
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use anchor_lang::solana_program::{bpf_loader, system_program};
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use zeitgeist::errors::SocialRouletteError;
+
+/// Wallet balance given to every funded test account
+pub const WALLET_LAMPORTS: u64 = 1_000_000_000_000;
+
+thread_local! {
+    static CLOCK: Cell<(i64, u64)> = const { Cell::new((1_700_000_000, 1)) };
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_log_data(&self, _fields: &[&[u8]]) {}
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let (unix_timestamp, slot) = CLOCK.with(Cell::get);
+        let clock = Clock { slot, unix_timestamp, ..Clock::default() };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        if instruction.program_id == spl_token::ID {
+            return invoke_token(instruction, account_infos, signers_seeds);
+        }
+        if instruction.program_id != system_program::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        invoke_system(instruction, account_infos, signers_seeds)
+    }
+}
+
+/// Whether `info` signed the instruction or is a PDA signed for by `signers_seeds`
+fn is_signed(info: &AccountInfo, signers_seeds: &[&[&[u8]]]) -> bool {
+    info.is_signer
+        || signers_seeds.iter().any(|seeds| {
+            Pubkey::create_program_address(seeds, &zeitgeist::ID).ok() == Some(*info.key)
+        })
+}
+
+/// Run the SPL Token processor over the instruction's accounts, with PDA
+/// signatures applied
+fn invoke_token(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let infos = instruction
+        .accounts
+        .iter()
+        .map(|meta| {
+            let mut info = account_infos
+                .iter()
+                .find(|info| *info.key == meta.pubkey)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?
+                .clone();
+            info.is_signer = meta.is_signer && is_signed(&info, signers_seeds);
+            Ok(info)
+        })
+        .collect::<std::result::Result<Vec<_>, ProgramError>>()?;
+    spl_token::processor::Processor::process(&spl_token::ID, &infos, &instruction.data)
+}
+
+/// The subset of the system program the program calls into
+fn invoke_system(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let info = |index: usize| -> std::result::Result<&AccountInfo, ProgramError> {
+        let key = instruction
+            .accounts
+            .get(index)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?
+            .pubkey;
+        account_infos
+            .iter()
+            .find(|info| *info.key == key)
+            .ok_or(ProgramError::NotEnoughAccountKeys)
+    };
+    let signed = |info: &AccountInfo| is_signed(info, signers_seeds);
+    let debit = |from: &AccountInfo, to: &AccountInfo, lamports: u64| -> ProgramResult {
+        if !signed(from) {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if *from.owner != system_program::ID || !from.data_is_empty() {
+            return Err(ProgramError::InvalidArgument);
+        }
+        let balance = from.lamports().checked_sub(lamports).ok_or(ProgramError::InsufficientFunds)?;
+        **from.try_borrow_mut_lamports()? = balance;
+        **to.try_borrow_mut_lamports()? += lamports;
+        Ok(())
+    };
+    let allocate = |account: &AccountInfo, space: u64, owner: &Pubkey| -> ProgramResult {
+        if !signed(account) {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if *account.owner != system_program::ID || !account.data_is_empty() {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+        account.realloc(space as usize, true)?;
+        account.assign(owner);
+        Ok(())
+    };
+
+    let data = &instruction.data;
+    let tag = u32::from_le_bytes(data[..4].try_into().unwrap());
+    let u64_at = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+    let key_at = |offset: usize| Pubkey::try_from(&data[offset..offset + 32]).unwrap();
+
+    match tag {
+        // CreateAccount { lamports, space, owner }
+        0 => {
+            let (from, to) = (info(0)?, info(1)?);
+            if to.lamports() > 0 {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            debit(from, to, u64_at(4))?;
+            allocate(to, u64_at(12), &key_at(20))
+        }
+        // Assign { owner }
+        1 => {
+            let account = info(0)?;
+            if !signed(account) {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            account.assign(&key_at(4));
+            Ok(())
+        }
+        // Transfer { lamports }
+        2 => debit(info(0)?, info(1)?, u64_at(4)),
+        // Allocate { space }
+        8 => {
+            let account = info(0)?;
+            allocate(account, u64_at(4), &system_program::ID)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Account as stored between instructions
+#[derive(Clone)]
+struct StoredAccount {
+    lamports: u64,
+    data: Vec<u8>,
+    owner: Pubkey,
+    executable: bool,
+}
+
+/// Key laid out as the runtime serializes it, with the original data length
+/// just before it for `AccountInfo::realloc`
+#[repr(C)]
+struct SerializedKey {
+    _padding: u32,
+    original_data_len: u32,
+    key: Pubkey,
+}
+
+/// In-memory ledger that instructions are processed against
+pub struct Bank {
+    accounts: HashMap<Pubkey, StoredAccount>,
+}
+
+impl Bank {
+    /// Ledger holding only the system program, the token program and this program
+    pub fn genesis() -> Self {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(Stubs));
+        });
+
+        let mut bank = Self { accounts: HashMap::new() };
+        bank.add_program(system_program::ID);
+        bank.add_program(spl_token::ID);
+        bank.add_program(zeitgeist::ID);
+        bank
+    }
+
+    fn add_program(&mut self, key: Pubkey) {
+        self.accounts.insert(
+            key,
+            StoredAccount { lamports: 1, data: vec![], owner: bpf_loader::ID, executable: true },
+        );
+    }
+
+    /// Create a system account holding `WALLET_LAMPORTS`
+    pub fn wallet(&mut self) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.fund(key, WALLET_LAMPORTS);
+        key
+    }
+
+    /// Credit lamports to a system-owned account
+    pub fn fund(&mut self, key: Pubkey, lamports: u64) {
+        self.accounts
+            .entry(key)
+            .or_insert(StoredAccount {
+                lamports: 0,
+                data: vec![],
+                owner: system_program::ID,
+                executable: false,
+            })
+            .lamports += lamports;
+    }
+
+    /// Create an initialized, empty token account for `mint` owned by `owner`
+    pub fn token_account(&mut self, mint: Pubkey, owner: Pubkey) -> Pubkey {
+        let key = Pubkey::new_unique();
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        spl_token::state::Account {
+            mint,
+            owner,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+        self.accounts.insert(
+            key,
+            StoredAccount {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: spl_token::ID,
+                executable: false,
+            },
+        );
+        key
+    }
+
+    pub fn token_balance(&self, key: &Pubkey) -> u64 {
+        let account = self.accounts.get(key).expect("account not found");
+        spl_token::state::Account::unpack(&account.data).expect("not a token account").amount
+    }
+
+    pub fn mint_supply(&self, key: &Pubkey) -> u64 {
+        let account = self.accounts.get(key).expect("account not found");
+        spl_token::state::Mint::unpack(&account.data).expect("not a mint").supply
+    }
+
+    pub fn set_time(&self, unix_timestamp: i64) {
+        CLOCK.with(|clock| clock.set((unix_timestamp, clock.get().1)));
+    }
+
+    pub fn set_slot(&self, slot: u64) {
+        CLOCK.with(|clock| clock.set((clock.get().0, slot)));
+    }
+
+    pub fn now(&self) -> i64 {
+        CLOCK.with(Cell::get).0
+    }
+
+    pub fn lamports(&self, key: &Pubkey) -> u64 {
+        self.accounts.get(key).map_or(0, |account| account.lamports)
+    }
+
+    pub fn exists(&self, key: &Pubkey) -> bool {
+        self.accounts.contains_key(key)
+    }
+
+    pub fn get<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        let account = self.accounts.get(key).expect("account not found");
+        assert_eq!(account.owner, zeitgeist::ID, "account not owned by the program");
+        T::try_deserialize(&mut account.data.as_slice()).expect("account failed to deserialize")
+    }
+
+    /// Run one instruction. State is only committed if it succeeds.
+    pub fn process(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        args: impl InstructionData,
+        remaining: &[AccountMeta],
+    ) -> ProgramResult {
+        let mut metas = accounts.to_account_metas(None);
+        metas.extend_from_slice(remaining);
+
+        let mut by_key: HashMap<Pubkey, AccountInfo<'static>> = HashMap::new();
+        for meta in &metas {
+            let merged = metas.iter().filter(|other| other.pubkey == meta.pubkey);
+            let is_signer = merged.clone().any(|other| other.is_signer);
+            let is_writable = merged.clone().any(|other| other.is_writable);
+            by_key
+                .entry(meta.pubkey)
+                .or_insert_with(|| self.account_info(meta.pubkey, is_signer, is_writable));
+        }
+        let infos: &'static [AccountInfo<'static>] = Box::leak(
+            metas
+                .iter()
+                .map(|meta| by_key[&meta.pubkey].clone())
+                .collect::<Vec<_>>()
+                .into_boxed_slice(),
+        );
+
+        zeitgeist::entry(&zeitgeist::ID, infos, &args.data())?;
+
+        for (key, info) in by_key {
+            if info.lamports() == 0 {
+                self.accounts.remove(&key);
+                continue;
+            }
+            self.accounts.insert(
+                key,
+                StoredAccount {
+                    lamports: info.lamports(),
+                    data: info.data.borrow().to_vec(),
+                    owner: *info.owner,
+                    executable: info.executable,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    fn account_info(&self, key: Pubkey, is_signer: bool, is_writable: bool) -> AccountInfo<'static> {
+        let account = self.accounts.get(&key).cloned().unwrap_or(StoredAccount {
+            lamports: 0,
+            data: vec![],
+            owner: system_program::ID,
+            executable: false,
+        });
+        let len = account.data.len();
+
+        let key = Box::leak(Box::new(SerializedKey {
+            _padding: 0,
+            original_data_len: len as u32,
+            key,
+        }));
+
+        // Length prefix, data, then room to grow as the runtime allows
+        let buffer = Box::leak(vec![0u8; 8 + len + MAX_PERMITTED_DATA_INCREASE].into_boxed_slice());
+        buffer[..8].copy_from_slice(&(len as u64).to_le_bytes());
+        buffer[8..8 + len].copy_from_slice(&account.data);
+
+        AccountInfo::new(
+            &key.key,
+            is_signer,
+            is_writable,
+            Box::leak(Box::new(account.lamports)),
+            &mut buffer[8..8 + len],
+            Box::leak(Box::new(account.owner)),
+            account.executable,
+            0,
+        )
+    }
+}
+
+/// The error a failed instruction returns for `error`
+pub fn program_error(error: SocialRouletteError) -> ProgramError {
+    ProgramError::Custom(error.into())
+}
+
+/// The error a failed instruction returns for an account constraint `error`
+pub fn anchor_error(error: anchor_lang::error::ErrorCode) -> ProgramError {
+    ProgramError::Custom(error.into())
+}
@@ -0,0 +1,243 @@
+// Instruction tests: whole instructions run against an in-memory ledger
+
+mod amm;
+mod balances;
+mod crank;
+mod harness;
+mod moments;
+mod parlays;
+mod refunds;
+mod rounds;
+mod seasons;
+mod sessions;
+mod settlement;
+mod tokens;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::system_program;
+use harness::Bank;
+use zeitgeist::constants::*;
+use zeitgeist::state::VerificationMethod;
+use zeitgeist::{accounts, instruction};
+
+pub const SOL: u64 = 1_000_000_000;
+pub const BETTING_WINDOW: i64 = 60;
+pub const ROUND_DURATION: i64 = 600;
+
+pub fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &zeitgeist::ID).0
+}
+
+pub fn global_state() -> Pubkey {
+    pda(&[GLOBAL_STATE_SEED])
+}
+
+pub fn roles() -> Pubkey {
+    pda(&[ROLES_SEED])
+}
+
+pub fn round(round_id: u64) -> Pubkey {
+    pda(&[ROUND_SEED, &round_id.to_le_bytes()])
+}
+
+pub fn vault(round_id: u64) -> Pubkey {
+    pda(&[VAULT_SEED, &round_id.to_le_bytes()])
+}
+
+pub fn prediction(round_id: u64, user: &Pubkey) -> Pubkey {
+    pda(&[PREDICTION_SEED, &round_id.to_le_bytes(), user.as_ref()])
+}
+
+pub fn user_stats(user: &Pubkey) -> Pubkey {
+    pda(&[USER_STATS_SEED, user.as_ref()])
+}
+
+/// Who signs for and funds a bet placed for a user
+pub struct Funding {
+    pub payer: Pubkey,
+    pub session_token: Option<Pubkey>,
+    pub balance: Option<Pubkey>,
+}
+
+impl Funding {
+    /// The user signs and pays from their wallet
+    pub fn wallet(user: Pubkey) -> Self {
+        Self { payer: user, session_token: None, balance: None }
+    }
+}
+
+/// An initialized program whose admin holds every role
+pub struct Program {
+    pub bank: Bank,
+    pub admin: Pubkey,
+    pub platform_wallet: Pubkey,
+}
+
+impl Program {
+    pub fn initialize() -> Self {
+        let mut bank = Bank::genesis();
+        let admin = bank.wallet();
+        let platform_wallet = bank.wallet();
+
+        bank.process(
+            accounts::Initialize {
+                global_state: global_state(),
+                admin,
+                system_program: system_program::ID,
+            },
+            instruction::Initialize { platform_wallet },
+            &[],
+        )
+        .unwrap();
+        bank.process(
+            accounts::InitializeRoles {
+                global_state: global_state(),
+                roles: roles(),
+                admin,
+                system_program: system_program::ID,
+            },
+            instruction::InitializeRoles {},
+            &[],
+        )
+        .unwrap();
+
+        Self { bank, admin, platform_wallet }
+    }
+
+    /// Create a round opening now, settled by `data_source` through `verification_method`
+    pub fn create_round(
+        &mut self,
+        round_id: u64,
+        num_outcomes: u8,
+        verification_method: VerificationMethod,
+        target_value: i64,
+        data_source: Pubkey,
+    ) -> ProgramResult {
+        let start_time = self.bank.now() + 1;
+        self.bank.process(
+            accounts::CreateRound {
+                global_state: global_state(),
+                roles: roles(),
+                round: round(round_id),
+                vault: vault(round_id),
+                creator: self.admin,
+                system_program: system_program::ID,
+            },
+            instruction::CreateRound {
+                round_id,
+                start_time,
+                end_time: start_time + ROUND_DURATION,
+                num_outcomes,
+                description: format!("Round {round_id}"),
+                verification_method,
+                target_value,
+                data_source,
+                oracle: self.admin,
+                betting_window_duration: BETTING_WINDOW,
+            },
+            &[],
+        )?;
+        self.bank.set_time(start_time);
+        Ok(())
+    }
+
+    /// Create a round settled on-chain by slot: outcome 0 wins at or past `target_slot`
+    pub fn create_slot_round(&mut self, round_id: u64, target_slot: i64) {
+        self.create_round(round_id, 2, VerificationMethod::OnChainData, target_slot, Pubkey::default())
+            .unwrap();
+    }
+
+    pub fn place_prediction(&mut self, round_id: u64, user: Pubkey, outcome: u8, amount: u64) -> ProgramResult {
+        self.place_prediction_funded(round_id, user, Funding::wallet(user), outcome, amount)
+    }
+
+    pub fn place_prediction_funded(
+        &mut self,
+        round_id: u64,
+        user: Pubkey,
+        funding: Funding,
+        outcome: u8,
+        amount: u64,
+    ) -> ProgramResult {
+        self.bank.process(
+            accounts::PlacePrediction {
+                global_state: global_state(),
+                round: round(round_id),
+                prediction: prediction(round_id, &user),
+                user_stats: user_stats(&user),
+                vault: vault(round_id),
+                payer: funding.payer,
+                user,
+                session_token: funding.session_token,
+                balance: funding.balance,
+                moment_card: None,
+                card_merkle_tree: None,
+                compression_program: None,
+                system_program: system_program::ID,
+            },
+            instruction::PlacePrediction {
+                round_id,
+                outcome,
+                amount,
+                min_implied_multiplier_bps: None,
+                card_root: None,
+            },
+            &[],
+        )
+    }
+
+    /// Close betting and move past the round's end
+    pub fn close_betting(&mut self, round_id: u64) {
+        let end_time = self.bank.get::<zeitgeist::state::Round>(&round(round_id)).end_time;
+        self.bank.set_time(end_time);
+        self.bank
+            .process(
+                accounts::CloseBetting {
+                    global_state: global_state(),
+                    roles: roles(),
+                    round: round(round_id),
+                    authority: self.admin,
+                },
+                instruction::CloseBetting { round_id },
+                &[],
+            )
+            .unwrap();
+    }
+
+    pub fn settle_round(&mut self, round_id: u64, oracle_data: Pubkey) -> ProgramResult {
+        self.bank.process(
+            accounts::SettleRound {
+                global_state: global_state(),
+                roles: roles(),
+                round: round(round_id),
+                vault: vault(round_id),
+                platform_wallet: self.platform_wallet,
+                oracle_data,
+                oracle: self.admin,
+                authority: self.admin,
+                system_program: system_program::ID,
+            },
+            instruction::SettleRound { round_id },
+            &[],
+        )
+    }
+
+    pub fn claim_winnings(&mut self, round_id: u64, user: Pubkey) -> ProgramResult {
+        self.bank.process(
+            accounts::ClaimWinnings {
+                global_state: global_state(),
+                round: round(round_id),
+                prediction: prediction(round_id, &user),
+                user_stats: user_stats(&user),
+                vault: vault(round_id),
+                user,
+                balance: None,
+                perk_vault: None,
+                system_program: system_program::ID,
+            },
+            instruction::ClaimWinnings { round_id },
+            &[],
+        )
+    }
+}
@@ -0,0 +1,52 @@
+// Moment card rarity inputs
+
+use anchor_lang::prelude::*;
+use zeitgeist::state::{Prediction, UserStats};
+use zeitgeist::{accounts, instruction};
+
+use crate::{global_state, prediction, round, user_stats, Program, SOL};
+
+impl Program {
+    fn record_loss(&mut self, round_id: u64, user: Pubkey) {
+        self.bank
+            .process(
+                accounts::RecordLoss {
+                    global_state: global_state(),
+                    round: round(round_id),
+                    prediction: prediction(round_id, &user),
+                    user_stats: user_stats(&user),
+                    user,
+                },
+                instruction::RecordLoss { round_id },
+                &[],
+            )
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_comeback_streak_is_snapshotted_when_the_position_opens() {
+    let mut program = Program::initialize();
+    let (alice, bob) = (program.bank.wallet(), program.bank.wallet());
+
+    program.create_slot_round(1, 100);
+    program.place_prediction(1, alice, 1, SOL).unwrap();
+    program.place_prediction(1, bob, 0, SOL).unwrap();
+    program.close_betting(1);
+    program.bank.set_slot(100);
+    program.settle_round(1, Pubkey::default()).unwrap();
+    program.record_loss(1, alice);
+    assert_eq!(program.bank.get::<UserStats>(&user_stats(&alice)).loss_streak, 1);
+
+    program.create_slot_round(2, 100);
+    program.place_prediction(2, alice, 0, SOL).unwrap();
+    program.place_prediction(2, bob, 1, SOL).unwrap();
+    assert_eq!(program.bank.get::<Prediction>(&prediction(2, &alice)).loss_streak_at_entry, 1);
+
+    // Claiming the win resets the live streak but not the position's snapshot
+    program.close_betting(2);
+    program.settle_round(2, Pubkey::default()).unwrap();
+    program.claim_winnings(2, alice).unwrap();
+    assert_eq!(program.bank.get::<UserStats>(&user_stats(&alice)).loss_streak, 0);
+    assert_eq!(program.bank.get::<Prediction>(&prediction(2, &alice)).loss_streak_at_entry, 1);
+}
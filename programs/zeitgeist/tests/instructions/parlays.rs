@@ -0,0 +1,167 @@
+// Parlay placement and settlement
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use anchor_lang::solana_program::system_program;
+use zeitgeist::constants::{PARLAY_POOL_SEED, PARLAY_SEED, PARLAY_VAULT_SEED};
+use zeitgeist::state::{Parlay, ParlayPick, ParlayPool, Round};
+use zeitgeist::utils::calculate_winnings;
+use zeitgeist::{accounts, instruction};
+
+use crate::{global_state, pda, prediction, roles, round, user_stats, vault, Program, SOL};
+
+fn parlay_pool() -> Pubkey {
+    pda(&[PARLAY_POOL_SEED])
+}
+
+fn parlay_vault() -> Pubkey {
+    pda(&[PARLAY_VAULT_SEED])
+}
+
+fn parlay(user: &Pubkey, parlay_id: u64) -> Pubkey {
+    pda(&[PARLAY_SEED, user.as_ref(), &parlay_id.to_le_bytes()])
+}
+
+fn rounds(round_ids: &[u64]) -> Vec<AccountMeta> {
+    round_ids
+        .iter()
+        .map(|round_id| AccountMeta::new_readonly(round(*round_id), false))
+        .collect()
+}
+
+impl Program {
+    fn fund_parlay_pool(&mut self, amount: u64) {
+        self.bank
+            .process(
+                accounts::FundParlayPool {
+                    roles: roles(),
+                    parlay_pool: parlay_pool(),
+                    vault: parlay_vault(),
+                    treasurer: self.admin,
+                    system_program: system_program::ID,
+                },
+                instruction::FundParlayPool { amount },
+                &[],
+            )
+            .unwrap();
+    }
+
+    fn place_parlay(
+        &mut self,
+        user: Pubkey,
+        parlay_id: u64,
+        picks: Vec<ParlayPick>,
+        amount: u64,
+    ) -> ProgramResult {
+        let round_ids: Vec<u64> = picks.iter().map(|pick| pick.round_id).collect();
+        self.bank.process(
+            accounts::PlaceParlay {
+                global_state: global_state(),
+                parlay_pool: parlay_pool(),
+                parlay: parlay(&user, parlay_id),
+                vault: parlay_vault(),
+                user,
+                system_program: system_program::ID,
+            },
+            instruction::PlaceParlay { parlay_id, picks, amount, min_payout: 0 },
+            &rounds(&round_ids),
+        )
+    }
+
+    fn settle_parlay(&mut self, user: Pubkey, parlay_id: u64, round_ids: &[u64]) -> ProgramResult {
+        self.bank.process(
+            accounts::SettleParlay {
+                global_state: global_state(),
+                parlay_pool: parlay_pool(),
+                parlay: parlay(&user, parlay_id),
+                vault: parlay_vault(),
+                user,
+                system_program: system_program::ID,
+            },
+            instruction::SettleParlay { parlay_id },
+            &rounds(round_ids),
+        )
+    }
+
+    fn withdraw_prediction(
+        &mut self,
+        round_id: u64,
+        user: Pubkey,
+        outcome: u8,
+        amount: u64,
+    ) -> ProgramResult {
+        self.bank.process(
+            accounts::WithdrawPrediction {
+                global_state: global_state(),
+                round: round(round_id),
+                prediction: prediction(round_id, &user),
+                user_stats: user_stats(&user),
+                vault: vault(round_id),
+                user,
+                system_program: system_program::ID,
+            },
+            instruction::WithdrawPrediction { round_id, outcome, amount },
+            &[],
+        )
+    }
+}
+
+/// What one unit staked on the winner of a settled round was paid, in basis points
+fn closing_multiplier_bps(round: &Round) -> u64 {
+    calculate_winnings(10000, round.winning_pool, round.total_pool, round.platform_fee_collected).unwrap()
+}
+
+#[test]
+fn test_withdrawing_after_placing_pays_closing_odds() {
+    let mut program = Program::initialize();
+    let (alice, bob, carol) = (program.bank.wallet(), program.bank.wallet(), program.bank.wallet());
+    program.fund_parlay_pool(100 * SOL);
+
+    program.create_slot_round(1, 100);
+    program.create_slot_round(2, 100);
+    program.place_prediction(1, alice, 0, SOL).unwrap();
+    program.place_prediction(2, alice, 0, SOL).unwrap();
+    program.place_prediction(2, bob, 1, SOL).unwrap();
+
+    // Bob props up the opposing side of round 1 only while the parlay is quoted
+    program.place_prediction(1, bob, 1, 9 * SOL).unwrap();
+    let picks = vec![
+        ParlayPick { round_id: 1, outcome: 0 },
+        ParlayPick { round_id: 2, outcome: 0 },
+    ];
+    program.place_parlay(carol, 1, picks, SOL).unwrap();
+    program.withdraw_prediction(1, bob, 1, 8 * SOL).unwrap();
+
+    let quoted: Parlay = program.bank.get(&parlay(&carol, 1));
+    assert_eq!(quoted.legs[0].multiplier_bps, zeitgeist::constants::MAX_PARLAY_LEG_MULTIPLIER_BPS);
+
+    for round_id in [1, 2] {
+        program.close_betting(round_id);
+    }
+    program.bank.set_slot(100);
+    for round_id in [1, 2] {
+        program.settle_round(round_id, Pubkey::default()).unwrap();
+    }
+
+    // Each leg pays the lower of its quote and its round's closing odds
+    let legs: Vec<u64> = [1, 2]
+        .iter()
+        .zip(&quoted.legs)
+        .map(|(round_id, leg)| {
+            let settled: Round = program.bank.get(&round(*round_id));
+            leg.multiplier_bps.min(closing_multiplier_bps(&settled))
+        })
+        .collect();
+    let payout = Parlay::payout_for(SOL, Parlay::combined_multiplier_bps(&legs).unwrap()).unwrap();
+    assert!(payout < quoted.potential_payout / 2);
+
+    let parlay_rent = Rent::default().minimum_balance(8 + Parlay::INIT_SPACE);
+    let before = program.bank.lamports(&carol);
+    program.settle_parlay(carol, 1, &[1, 2]).unwrap();
+    assert_eq!(program.bank.lamports(&carol) - before, payout + parlay_rent);
+
+    let pool: ParlayPool = program.bank.get(&parlay_pool());
+    assert_eq!((pool.total_liability, pool.total_paid), (0, payout));
+    assert!(!program.bank.exists(&parlay(&carol, 1)));
+}
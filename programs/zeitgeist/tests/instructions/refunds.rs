@@ -0,0 +1,72 @@
+// Refunds on cancelled rounds
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::system_program;
+use zeitgeist::constants::XP_PER_PREDICTION;
+use zeitgeist::state::{Prediction, UserStats};
+use zeitgeist::{accounts, instruction};
+
+use crate::{global_state, prediction, roles, round, user_stats, vault, Program, SOL};
+
+impl Program {
+    fn emergency_cancel(&mut self, round_id: u64) {
+        self.bank
+            .process(
+                accounts::EmergencyCancel {
+                    global_state: global_state(),
+                    roles: roles(),
+                    round: round(round_id),
+                    authority: self.admin,
+                },
+                instruction::EmergencyCancel { round_id, reason: "test".to_string() },
+                &[],
+            )
+            .unwrap();
+    }
+
+    fn refund_prediction(&mut self, round_id: u64, user: Pubkey) -> ProgramResult {
+        self.bank.process(
+            accounts::RefundPrediction {
+                global_state: global_state(),
+                round: round(round_id),
+                prediction: prediction(round_id, &user),
+                user_stats: user_stats(&user),
+                vault: vault(round_id),
+                user,
+                balance: None,
+                system_program: system_program::ID,
+            },
+            instruction::RefundPrediction { round_id },
+            &[],
+        )
+    }
+}
+
+#[test]
+fn test_refunded_player_keeps_stats_on_next_bet() {
+    let mut program = Program::initialize();
+    let alice = program.bank.wallet();
+    program.create_slot_round(1, 100);
+    program.place_prediction(1, alice, 0, SOL).unwrap();
+    program.emergency_cancel(1);
+
+    let prediction_rent = Rent::default().minimum_balance(8 + Prediction::INIT_SPACE);
+    let before = program.bank.lamports(&alice);
+    program.refund_prediction(1, alice).unwrap();
+    assert_eq!(program.bank.lamports(&alice) - before, SOL + prediction_rent);
+
+    // The refund leaves no predictions counted, which must not read as a fresh account
+    let refunded: UserStats = program.bank.get(&user_stats(&alice));
+    assert_eq!((refunded.total_predictions, refunded.total_refunded), (0, SOL));
+
+    program.create_slot_round(2, 100);
+    program.place_prediction(2, alice, 1, 2 * SOL).unwrap();
+
+    let stats: UserStats = program.bank.get(&user_stats(&alice));
+    assert_eq!(stats.user, alice);
+    assert_eq!(stats.total_refunded, SOL);
+    assert_eq!((stats.total_predictions, stats.pending_stake), (1, 2 * SOL));
+    assert_eq!(stats.xp, 2 * XP_PER_PREDICTION);
+    assert_eq!(stats.net_profit, 0);
+}
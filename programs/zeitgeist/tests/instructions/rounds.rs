@@ -0,0 +1,140 @@
+// Round creation and id assignment
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::system_program;
+use zeitgeist::constants::{AUTO_ROUND_ID_BASE, ROUND_LOOKUP_SEED, ROUND_SERIES_SEED};
+use zeitgeist::errors::SocialRouletteError;
+use zeitgeist::state::{GlobalState, Round, RoundLookup, RoundSeries, VerificationMethod};
+use zeitgeist::{accounts, instruction};
+
+use crate::harness::program_error;
+use crate::{global_state, pda, roles, round, vault, Program, BETTING_WINDOW, ROUND_DURATION};
+
+fn create_round_auto(program: &mut Program, client_nonce: u64) -> ProgramResult {
+    let round_id = Round::auto_round_id(program.bank.get::<GlobalState>(&global_state()).auto_rounds);
+    let start_time = program.bank.now() + 1;
+    program.bank.process(
+        accounts::CreateRoundAuto {
+            global_state: global_state(),
+            roles: roles(),
+            round: round(round_id),
+            round_lookup: round_lookup(&program.admin, client_nonce),
+            vault: vault(round_id),
+            creator: program.admin,
+            system_program: system_program::ID,
+        },
+        instruction::CreateRoundAuto {
+            client_nonce,
+            start_time,
+            end_time: start_time + ROUND_DURATION,
+            num_outcomes: 2,
+            description: format!("Auto round {client_nonce}"),
+            verification_method: VerificationMethod::OnChainData,
+            target_value: 100,
+            data_source: Pubkey::default(),
+            oracle: program.admin,
+            betting_window_duration: BETTING_WINDOW,
+        },
+        &[],
+    )
+}
+
+fn round_lookup(creator: &Pubkey, client_nonce: u64) -> Pubkey {
+    pda(&[ROUND_LOOKUP_SEED, creator.as_ref(), &client_nonce.to_le_bytes()])
+}
+
+#[test]
+fn test_auto_round_ids_are_sequential_and_reserved() {
+    let mut program = Program::initialize();
+
+    // Manual rounds in between don't advance the auto range
+    create_round_auto(&mut program, 1).unwrap();
+    program.create_slot_round(7, 100);
+    create_round_auto(&mut program, 2).unwrap();
+
+    for (client_nonce, round_id) in [(1, AUTO_ROUND_ID_BASE), (2, AUTO_ROUND_ID_BASE + 1)] {
+        let lookup: RoundLookup = program.bank.get(&round_lookup(&program.admin, client_nonce));
+        assert_eq!(lookup.round_id, round_id);
+        assert_eq!(program.bank.get::<Round>(&round(round_id)).round_id, round_id);
+    }
+    // Even the last auto id fits a signed BIGINT and a JS number
+    assert!(Round::auto_round_id(AUTO_ROUND_ID_BASE - 1) < 1 << 53);
+
+    let state: GlobalState = program.bank.get(&global_state());
+    assert_eq!(state.auto_rounds, 2);
+    assert_eq!(state.total_rounds, 3);
+
+    // The next auto id can't be taken by a manual round
+    assert_eq!(
+        program.create_round(AUTO_ROUND_ID_BASE + 2, 2, VerificationMethod::OnChainData, 100, Pubkey::default()),
+        Err(program_error(SocialRouletteError::ReservedRoundId))
+    );
+    create_round_auto(&mut program, 3).unwrap();
+    assert!(program.bank.exists(&round(AUTO_ROUND_ID_BASE + 2)));
+
+    // Retrying a nonce fails instead of creating a second round
+    assert!(create_round_auto(&mut program, 3).is_err());
+}
+
+fn series(series_id: u64) -> Pubkey {
+    pda(&[ROUND_SERIES_SEED, &series_id.to_le_bytes()])
+}
+
+#[test]
+fn test_manual_rounds_cant_squat_series_ids() {
+    let mut program = Program::initialize();
+    program
+        .bank
+        .process(
+            accounts::CreateRoundSeries {
+                global_state: global_state(),
+                roles: roles(),
+                series: series(1),
+                creator: program.admin,
+                system_program: system_program::ID,
+            },
+            instruction::CreateRoundSeries {
+                series_id: 1,
+                description: "Hourly".to_string(),
+                num_outcomes: 2,
+                verification_method: VerificationMethod::OnChainData,
+                target_value: 100,
+                data_source: Pubkey::default(),
+                oracle: program.admin,
+                cadence: ROUND_DURATION,
+                betting_window_duration: BETTING_WINDOW,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let first_spawn = RoundSeries::first_round_id(1).unwrap();
+    assert_eq!(
+        program.create_round(first_spawn, 2, VerificationMethod::OnChainData, 100, Pubkey::default()),
+        Err(program_error(SocialRouletteError::ReservedRoundId))
+    );
+    assert_eq!(
+        program.create_round(first_spawn + 1, 2, VerificationMethod::OnChainData, 100, Pubkey::default()),
+        Err(program_error(SocialRouletteError::ReservedRoundId))
+    );
+
+    // The series still spawns into its own range
+    program
+        .bank
+        .process(
+            accounts::SpawnNextRound {
+                global_state: global_state(),
+                series: series(1),
+                round: round(first_spawn),
+                vault: vault(first_spawn),
+                payer: program.admin,
+                system_program: system_program::ID,
+            },
+            instruction::SpawnNextRound { series_id: 1 },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(program.bank.get::<Round>(&round(first_spawn)).round_id, first_spawn);
+    assert_eq!(program.bank.get::<RoundSeries>(&series(1)).next_round_id, first_spawn + 1);
+}
@@ -0,0 +1,204 @@
+// Season scoring
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::system_program;
+use zeitgeist::constants::{SEASON_ENTRY_SEED, SEASON_SEED, SEASON_VAULT_SEED, SECONDS_PER_DAY};
+use zeitgeist::state::{LeaderboardEntry, Round, Season};
+use zeitgeist::errors::SocialRouletteError;
+use zeitgeist::utils::calculate_winnings;
+use zeitgeist::{accounts, instruction};
+
+use crate::harness::program_error;
+use crate::{global_state, pda, roles, round, user_stats, Program, SOL};
+
+fn season(season_id: u64) -> Pubkey {
+    pda(&[SEASON_SEED, &season_id.to_le_bytes()])
+}
+
+fn season_vault(season_id: u64) -> Pubkey {
+    pda(&[SEASON_VAULT_SEED, &season_id.to_le_bytes()])
+}
+
+fn season_entry(season_id: u64, user: &Pubkey) -> Pubkey {
+    pda(&[SEASON_ENTRY_SEED, &season_id.to_le_bytes(), user.as_ref()])
+}
+
+impl Program {
+    fn create_season(&mut self, season_id: u64, start_time: i64, reward_pool: u64) {
+        self.bank
+            .process(
+                accounts::CreateSeason {
+                    roles: roles(),
+                    season: season(season_id),
+                    vault: season_vault(season_id),
+                    treasurer: self.admin,
+                    system_program: system_program::ID,
+                },
+                instruction::CreateSeason {
+                    season_id,
+                    start_time,
+                    end_time: start_time + 30 * SECONDS_PER_DAY,
+                    global: false,
+                    reward_pool,
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    fn join_season(&mut self, season_id: u64, user: Pubkey) -> ProgramResult {
+        self.bank.process(
+            accounts::JoinSeason {
+                season: season(season_id),
+                season_entry: season_entry(season_id, &user),
+                user_stats: user_stats(&user),
+                user,
+                system_program: system_program::ID,
+            },
+            instruction::JoinSeason { season_id },
+            &[],
+        )
+    }
+
+    /// Submit `user`'s score and return the resulting leaderboard
+    fn submit_season_score(&mut self, season_id: u64, user: Pubkey) -> Vec<LeaderboardEntry> {
+        self.bank
+            .process(
+                accounts::SubmitSeasonScore {
+                    season: season(season_id),
+                    season_entry: season_entry(season_id, &user),
+                    user_stats: user_stats(&user),
+                },
+                instruction::SubmitSeasonScore { season_id, user },
+                &[],
+            )
+            .unwrap();
+        self.bank.get::<Season>(&season(season_id)).leaderboard
+    }
+
+    fn close_season(&mut self, season_id: u64) {
+        self.bank
+            .process(
+                accounts::CloseSeason {
+                    global_state: global_state(),
+                    season: season(season_id),
+                    vault: season_vault(season_id),
+                    platform_wallet: self.platform_wallet,
+                    system_program: system_program::ID,
+                },
+                instruction::CloseSeason { season_id },
+                &[],
+            )
+            .unwrap();
+    }
+
+    fn claim_season_reward(&mut self, season_id: u64, user: Pubkey) -> ProgramResult {
+        self.bank.process(
+            accounts::ClaimSeasonReward {
+                global_state: global_state(),
+                season: season(season_id),
+                vault: season_vault(season_id),
+                user,
+                system_program: system_program::ID,
+            },
+            instruction::ClaimSeasonReward { season_id },
+            &[],
+        )
+    }
+}
+
+#[test]
+fn test_unresolved_losses_score_as_lost() {
+    let mut program = Program::initialize();
+    let (alice, bob) = (program.bank.wallet(), program.bank.wallet());
+    let now = program.bank.now();
+    program.create_season(1, now, 10 * SOL);
+
+    // Both join with a stake already pending
+    program.create_slot_round(1, 100);
+    program.place_prediction(1, alice, 0, SOL).unwrap();
+    program.place_prediction(1, bob, 1, SOL).unwrap();
+    program.join_season(1, alice).unwrap();
+    program.join_season(1, bob).unwrap();
+
+    // Bob loses round 1 and stakes more on round 2, but neither loss is resolved
+    program.create_slot_round(2, 100);
+    program.place_prediction(2, bob, 1, 5 * SOL).unwrap();
+    for round_id in [1, 2] {
+        program.close_betting(round_id);
+    }
+    program.bank.set_slot(100);
+    for round_id in [1, 2] {
+        program.settle_round(round_id, Pubkey::default()).unwrap();
+    }
+    program.distribute_winnings(1, &[alice]).unwrap();
+
+    let settled: Round = program.bank.get(&round(1));
+    let alice_profit = calculate_winnings(SOL, SOL, 2 * SOL, settled.platform_fee_collected).unwrap() as i64;
+
+    program.submit_season_score(1, alice);
+    let board = program.submit_season_score(1, bob);
+    assert_eq!(board[0].user, alice);
+    assert_eq!(board[0].net_profit, alice_profit);
+    assert_eq!((board[1].user, board[1].net_profit, board[1].win_rate_bps), (bob, -5 * SOL as i64, 0));
+
+    // Resolving the losses later doesn't move the score
+    program.distribute_winnings(1, &[bob]).unwrap();
+    program.distribute_winnings(2, &[bob]).unwrap();
+    let board = program.submit_season_score(1, bob);
+    assert_eq!((board[1].user, board[1].net_profit, board[1].win_rate_bps), (bob, -5 * SOL as i64, 0));
+}
+
+#[test]
+fn test_ranked_players_claim_rewards_after_close() {
+    let mut program = Program::initialize();
+    let (alice, bob, carol) = (program.bank.wallet(), program.bank.wallet(), program.bank.wallet());
+    let start_time = program.bank.now() + 10;
+    program.create_season(1, start_time, 10 * SOL);
+
+    program.create_slot_round(1, 100);
+    for (user, outcome) in [(alice, 0), (bob, 1), (carol, 1)] {
+        program.place_prediction(1, user, outcome, SOL).unwrap();
+    }
+
+    // Nobody can join before the season starts
+    assert_eq!(program.join_season(1, alice), Err(program_error(SocialRouletteError::SeasonNotActive)));
+    program.bank.set_time(start_time);
+    program.join_season(1, alice).unwrap();
+    program.join_season(1, bob).unwrap();
+
+    program.close_betting(1);
+    program.bank.set_slot(100);
+    program.settle_round(1, Pubkey::default()).unwrap();
+    program.submit_season_score(1, alice);
+    program.submit_season_score(1, bob);
+    assert_eq!(
+        program.claim_season_reward(1, alice),
+        Err(program_error(SocialRouletteError::SeasonNotEnded))
+    );
+
+    // Closing returns only the unfilled ranks' shares to the treasury
+    let end_time = program.bank.get::<Season>(&season(1)).end_time;
+    program.bank.set_time(end_time);
+    let treasury_before = program.bank.lamports(&program.platform_wallet);
+    program.close_season(1);
+    let allocated = Season::reward_share(10 * SOL, 0).unwrap() + Season::reward_share(10 * SOL, 1).unwrap();
+    assert_eq!(program.bank.lamports(&program.platform_wallet) - treasury_before, 10 * SOL - allocated);
+
+    // Each ranked player claims their own share, once
+    for (rank, user) in [(0, alice), (1, bob)] {
+        let before = program.bank.lamports(&user);
+        program.claim_season_reward(1, user).unwrap();
+        assert_eq!(program.bank.lamports(&user) - before, Season::reward_share(10 * SOL, rank).unwrap());
+    }
+    assert_eq!(
+        program.claim_season_reward(1, alice),
+        Err(program_error(SocialRouletteError::AlreadyClaimed))
+    );
+    assert_eq!(
+        program.claim_season_reward(1, carol),
+        Err(program_error(SocialRouletteError::NoSeasonReward))
+    );
+    assert_eq!(program.bank.lamports(&season_vault(1)), Rent::default().minimum_balance(0));
+}
@@ -0,0 +1,121 @@
+// Session keys betting for a user
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::system_program;
+use zeitgeist::constants::SESSION_TOKEN_SEED;
+use zeitgeist::errors::SocialRouletteError;
+use zeitgeist::state::{Prediction, SessionToken};
+use zeitgeist::{accounts, instruction};
+
+use crate::harness::{anchor_error, program_error};
+use crate::{pda, prediction, Funding, Program, SOL};
+
+fn session_token(user: &Pubkey, session_key: &Pubkey) -> Pubkey {
+    pda(&[SESSION_TOKEN_SEED, user.as_ref(), session_key.as_ref()])
+}
+
+fn session(user: Pubkey, session_key: Pubkey) -> Funding {
+    Funding { payer: session_key, session_token: Some(session_token(&user, &session_key)), balance: None }
+}
+
+impl Program {
+    /// Authorize a fresh session key for `user`, funded to place its bets
+    fn create_session(&mut self, user: Pubkey, duration: i64, spend_cap: u64) -> Pubkey {
+        let session_key = Pubkey::new_unique();
+        self.bank
+            .process(
+                accounts::CreateSession {
+                    session_token: session_token(&user, &session_key),
+                    session_signer: session_key,
+                    user,
+                    system_program: system_program::ID,
+                },
+                instruction::CreateSession { session_key, duration, spend_cap, top_up: 10 * SOL },
+                &[],
+            )
+            .unwrap();
+        session_key
+    }
+
+    fn revoke_session(&mut self, user: Pubkey, session_key: Pubkey) -> ProgramResult {
+        self.bank.process(
+            accounts::RevokeSession { session_token: session_token(&user, &session_key), user },
+            instruction::RevokeSession { session_key },
+            &[],
+        )
+    }
+}
+
+#[test]
+fn test_session_key_bets_for_its_user_within_cap_until_revoked() {
+    let mut program = Program::initialize();
+    let alice = program.bank.wallet();
+    program.create_slot_round(1, 100);
+    let session_key = program.create_session(alice, 3600, 2 * SOL);
+
+    // The bet is the user's, paid by the session key
+    let before = program.bank.lamports(&session_key);
+    program.place_prediction_funded(1, alice, session(alice, session_key), 0, SOL).unwrap();
+    let position: Prediction = program.bank.get(&prediction(1, &alice));
+    assert_eq!((position.user, position.amount), (alice, SOL));
+    assert!(before - program.bank.lamports(&session_key) >= SOL);
+    assert_eq!(program.bank.get::<SessionToken>(&session_token(&alice, &session_key)).spent, SOL);
+
+    // The cap counts every bet placed through the session
+    assert_eq!(
+        program.place_prediction_funded(1, alice, session(alice, session_key), 0, SOL + 1),
+        Err(program_error(SocialRouletteError::SessionSpendCapExceeded))
+    );
+    program.place_prediction_funded(1, alice, session(alice, session_key), 1, SOL).unwrap();
+
+    // Revoking closes the token, so the key can't bet again
+    program.revoke_session(alice, session_key).unwrap();
+    assert!(!program.bank.exists(&session_token(&alice, &session_key)));
+    assert_eq!(
+        program.place_prediction_funded(1, alice, session(alice, session_key), 0, SOL),
+        Err(anchor_error(ErrorCode::AccountNotInitialized))
+    );
+}
+
+#[test]
+fn test_expired_session_is_rejected() {
+    let mut program = Program::initialize();
+    let alice = program.bank.wallet();
+    let session_key = program.create_session(alice, 30, 10 * SOL);
+    program.create_slot_round(1, 100);
+
+    program.bank.set_time(program.bank.now() + 30);
+    assert_eq!(
+        program.place_prediction_funded(1, alice, session(alice, session_key), 0, SOL),
+        Err(program_error(SocialRouletteError::SessionExpired))
+    );
+}
+
+#[test]
+fn test_session_key_cant_bet_for_another_user() {
+    let mut program = Program::initialize();
+    let (alice, bob) = (program.bank.wallet(), program.bank.wallet());
+    program.create_slot_round(1, 100);
+    let session_key = program.create_session(alice, 3600, 10 * SOL);
+
+    // Alice's token doesn't derive from Bob, and Bob never delegated
+    let alices_token = session(alice, session_key);
+    assert_eq!(
+        program.place_prediction_funded(1, bob, alices_token, 0, SOL),
+        Err(anchor_error(ErrorCode::ConstraintSeeds))
+    );
+    assert_eq!(
+        program.place_prediction_funded(1, bob, session(bob, session_key), 0, SOL),
+        Err(anchor_error(ErrorCode::AccountNotInitialized))
+    );
+
+    // Without a session, only the user may pay for their own bet
+    let unauthorized = Funding { payer: session_key, session_token: None, balance: None };
+    assert_eq!(
+        program.place_prediction_funded(1, bob, unauthorized, 0, SOL),
+        Err(program_error(SocialRouletteError::SessionUnauthorized))
+    );
+    assert!(!program.bank.exists(&prediction(1, &bob)));
+}
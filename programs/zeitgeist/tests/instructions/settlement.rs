@@ -0,0 +1,77 @@
+// Round settlement
+
+use anchor_lang::prelude::*;
+use zeitgeist::errors::SocialRouletteError;
+use zeitgeist::state::{Prediction, Round, RoundStatus, VerificationMethod};
+use zeitgeist::utils::{calculate_platform_fee, calculate_winnings};
+
+use crate::harness::program_error;
+use crate::{prediction, round, vault, Program, SOL};
+
+#[test]
+fn test_settlement_pays_out_the_winning_outcome_pool() {
+    let mut program = Program::initialize();
+    let (alice, bob, carol) = (program.bank.wallet(), program.bank.wallet(), program.bank.wallet());
+    program.create_slot_round(1, 100);
+
+    program.place_prediction(1, alice, 0, 3 * SOL).unwrap();
+    program.place_prediction(1, bob, 0, SOL).unwrap();
+    program.place_prediction(1, carol, 1, 4 * SOL).unwrap();
+
+    assert_eq!(
+        program.settle_round(1, Pubkey::default()),
+        Err(program_error(SocialRouletteError::BettingStillActive))
+    );
+
+    program.close_betting(1);
+    program.bank.set_slot(100);
+    let fee_before = program.bank.lamports(&program.platform_wallet);
+    program.settle_round(1, Pubkey::default()).unwrap();
+
+    let settled: Round = program.bank.get(&round(1));
+    let fee = calculate_platform_fee(8 * SOL, zeitgeist::constants::PLATFORM_FEE_BPS).unwrap();
+    assert!(matches!(settled.status, RoundStatus::Settled));
+    assert_eq!(settled.winning_outcome, 0);
+    assert_eq!(settled.winning_pool, settled.outcome_pools[0]);
+    assert_eq!(settled.winning_pool, 4 * SOL);
+    assert_eq!(program.bank.lamports(&program.platform_wallet) - fee_before, fee);
+
+    // Winners split everything but the fee
+    for (user, stake) in [(alice, 3 * SOL), (bob, SOL)] {
+        let before = program.bank.lamports(&user);
+        program.claim_winnings(1, user).unwrap();
+        assert_eq!(
+            program.bank.lamports(&user) - before,
+            calculate_winnings(stake, 4 * SOL, 8 * SOL, fee).unwrap()
+        );
+    }
+    assert_eq!(program.bank.lamports(&vault(1)), Rent::default().minimum_balance(0));
+
+    // Claimed predictions stay open for minting until closed, which returns their rent
+    let claimed: Prediction = program.bank.get(&prediction(1, &alice));
+    assert!(claimed.claimed);
+    assert_eq!(
+        program.claim_winnings(1, alice),
+        Err(program_error(SocialRouletteError::AlreadyClaimed))
+    );
+    let prediction_rent = Rent::default().minimum_balance(8 + Prediction::INIT_SPACE);
+    let before = program.bank.lamports(&alice);
+    program.close_prediction(1, alice).unwrap();
+    assert_eq!(program.bank.lamports(&alice) - before, prediction_rent);
+    assert!(!program.bank.exists(&prediction(1, &alice)));
+}
+
+#[test]
+fn test_settlement_reads_the_rounds_own_feed() {
+    let mut program = Program::initialize();
+    let feed = Pubkey::new_unique();
+    program
+        .create_round(1, 2, VerificationMethod::PythPrice, 100, feed)
+        .unwrap();
+    program.close_betting(1);
+
+    assert_eq!(
+        program.settle_round(1, Pubkey::new_unique()),
+        Err(program_error(SocialRouletteError::InvalidOracle))
+    );
+}
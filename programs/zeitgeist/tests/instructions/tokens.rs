@@ -0,0 +1,181 @@
+// Outcome tokens
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::spl_token;
+use zeitgeist::constants::OUTCOME_MINT_SEED;
+use zeitgeist::state::Round;
+use zeitgeist::{accounts, instruction};
+
+use crate::harness::anchor_error;
+use crate::{global_state, pda, round, vault, Program, SOL};
+
+fn outcome_mint(round_id: u64, outcome: u8) -> Pubkey {
+    pda(&[OUTCOME_MINT_SEED, &round_id.to_le_bytes(), &[outcome]])
+}
+
+/// `(outcome mint, token account)` pairs, one per outcome
+fn outcome_pairs(round_id: u64, token_accounts: &[Pubkey]) -> Vec<AccountMeta> {
+    (0u8..)
+        .zip(token_accounts)
+        .flat_map(|(outcome, token_account)| {
+            [
+                AccountMeta::new(outcome_mint(round_id, outcome), false),
+                AccountMeta::new(*token_account, false),
+            ]
+        })
+        .collect()
+}
+
+impl Program {
+    /// Create a slot round with a mint for each of its two outcomes
+    fn create_tokenized_round(&mut self, round_id: u64, target_slot: i64) {
+        self.create_slot_round(round_id, target_slot);
+        for outcome in 0..2 {
+            self.bank
+                .process(
+                    accounts::CreateOutcomeMint {
+                        global_state: global_state(),
+                        round: round(round_id),
+                        outcome_mint: outcome_mint(round_id, outcome),
+                        creator: self.admin,
+                        token_program: spl_token::ID,
+                        system_program: system_program::ID,
+                    },
+                    instruction::CreateOutcomeMint { round_id, outcome },
+                    &[],
+                )
+                .unwrap();
+        }
+    }
+
+    /// A token account for every outcome of the round, owned by `user`
+    fn outcome_token_accounts(&mut self, round_id: u64, user: Pubkey) -> Vec<Pubkey> {
+        (0..2)
+            .map(|outcome| self.bank.token_account(outcome_mint(round_id, outcome), user))
+            .collect()
+    }
+
+    fn mint_complete_set(&mut self, round_id: u64, user: Pubkey, token_accounts: &[Pubkey], amount: u64) {
+        self.bank
+            .process(
+                accounts::MintCompleteSet {
+                    global_state: global_state(),
+                    round: round(round_id),
+                    vault: vault(round_id),
+                    user,
+                    token_program: spl_token::ID,
+                    system_program: system_program::ID,
+                },
+                instruction::MintCompleteSet { round_id, amount },
+                &outcome_pairs(round_id, token_accounts),
+            )
+            .unwrap();
+    }
+
+    fn burn_complete_set(&mut self, round_id: u64, user: Pubkey, token_accounts: &[Pubkey], amount: u64) {
+        self.bank
+            .process(
+                accounts::BurnCompleteSet {
+                    global_state: global_state(),
+                    round: round(round_id),
+                    vault: vault(round_id),
+                    user,
+                    token_program: spl_token::ID,
+                    system_program: system_program::ID,
+                },
+                instruction::BurnCompleteSet { round_id, amount },
+                &outcome_pairs(round_id, token_accounts),
+            )
+            .unwrap();
+    }
+
+    fn redeem_outcome_tokens(
+        &mut self,
+        round_id: u64,
+        user: Pubkey,
+        user_token_account: Pubkey,
+        amount: u64,
+    ) -> ProgramResult {
+        let winning_outcome = self.bank.get::<Round>(&round(round_id)).winning_outcome;
+        self.bank.process(
+            accounts::RedeemOutcomeTokens {
+                global_state: global_state(),
+                round: round(round_id),
+                winning_mint: outcome_mint(round_id, winning_outcome),
+                user_token_account,
+                vault: vault(round_id),
+                user,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            instruction::RedeemOutcomeTokens { round_id, amount },
+            &[],
+        )
+    }
+}
+
+#[test]
+fn test_complete_set_burns_for_its_full_collateral() {
+    let mut program = Program::initialize();
+    let alice = program.bank.wallet();
+    program.create_tokenized_round(1, 100);
+    let tokens = program.outcome_token_accounts(1, alice);
+
+    let (wallet_before, vault_before) = (program.bank.lamports(&alice), program.bank.lamports(&vault(1)));
+    program.mint_complete_set(1, alice, &tokens, 3 * SOL);
+    for (outcome, token_account) in (0..2).zip(&tokens) {
+        assert_eq!(program.bank.token_balance(token_account), 3 * SOL);
+        assert_eq!(program.bank.mint_supply(&outcome_mint(1, outcome)), 3 * SOL);
+    }
+    assert_eq!(program.bank.get::<Round>(&round(1)).token_collateral, 3 * SOL);
+    assert_eq!(program.bank.lamports(&vault(1)) - vault_before, 3 * SOL);
+
+    program.burn_complete_set(1, alice, &tokens, 3 * SOL);
+    for token_account in &tokens {
+        assert_eq!(program.bank.token_balance(token_account), 0);
+    }
+    assert_eq!(program.bank.get::<Round>(&round(1)).token_collateral, 0);
+    assert_eq!(program.bank.lamports(&vault(1)), vault_before);
+    assert_eq!(program.bank.lamports(&alice), wallet_before);
+}
+
+#[test]
+fn test_winning_tokens_redeem_after_settlement() {
+    let mut program = Program::initialize();
+    let (alice, bob) = (program.bank.wallet(), program.bank.wallet());
+    program.create_tokenized_round(1, 100);
+    let alice_tokens = program.outcome_token_accounts(1, alice);
+    let bob_tokens = program.outcome_token_accounts(1, bob);
+    program.mint_complete_set(1, alice, &alice_tokens, 2 * SOL);
+    program.mint_complete_set(1, bob, &bob_tokens, SOL);
+
+    // Until settlement there's no winning mint to redeem against
+    assert_eq!(
+        program.redeem_outcome_tokens(1, alice, alice_tokens[0], 2 * SOL),
+        Err(anchor_error(ErrorCode::AccountNotInitialized))
+    );
+
+    program.close_betting(1);
+    program.bank.set_slot(100);
+    program.settle_round(1, Pubkey::default()).unwrap();
+
+    // Losing tokens have no mint to redeem against
+    assert_eq!(
+        program.redeem_outcome_tokens(1, alice, alice_tokens[1], 2 * SOL),
+        Err(anchor_error(ErrorCode::ConstraintTokenMint))
+    );
+
+    // Winning tokens split the whole collateral
+    for (user, token_account, amount) in [(alice, alice_tokens[0], 2 * SOL), (bob, bob_tokens[0], SOL)] {
+        let before = program.bank.lamports(&user);
+        program.redeem_outcome_tokens(1, user, token_account, amount).unwrap();
+        assert_eq!(program.bank.lamports(&user) - before, amount);
+        assert_eq!(program.bank.token_balance(&token_account), 0);
+    }
+    assert_eq!(program.bank.get::<Round>(&round(1)).token_collateral, 0);
+    assert_eq!(program.bank.mint_supply(&outcome_mint(1, 0)), 0);
+}